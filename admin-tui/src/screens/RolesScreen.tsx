@@ -13,14 +13,16 @@ import { PublicKey } from "@solana/web3.js";
 import type { RoleAccountData, StablecoinConfigAccount } from "@stbr/sss-token";
 
 type Mode = "menu" | "assign" | "revoke";
-type RoleKey = "burner" | "pauser" | "blacklister" | "seizer";
+type RoleKey = "burner" | "pauser" | "blacklister" | "seizer" | "minterController" | "allowlister";
 
-const ROLE_KEYS: RoleKey[] = ["burner", "pauser", "blacklister", "seizer"];
+const ROLE_KEYS: RoleKey[] = ["burner", "pauser", "blacklister", "seizer", "minterController", "allowlister"];
 const ROLE_LABELS: Record<RoleKey, string> = {
   burner: "Burner",
   pauser: "Pauser",
   blacklister: "Blacklister",
   seizer: "Seizer",
+  minterController: "Minter controller",
+  allowlister: "Allowlister",
 };
 
 interface MenuItem {
@@ -184,7 +186,7 @@ export function RolesScreen() {
           <Text bold color={theme.brandBright}>
             Roles
           </Text>
-          <Text color={theme.muted} dimColor>Master can assign or revoke burner, pauser, blacklister, seizer.</Text>
+          <Text color={theme.muted} dimColor>Master can assign or revoke burner, pauser, blacklister, seizer, minter controller, allowlister.</Text>
         </Box>
         <SectionHeader title="CURRENT ROLES" />
         <Box marginY={1} paddingLeft={1} flexDirection="column" gap={1}>
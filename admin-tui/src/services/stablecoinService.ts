@@ -53,6 +53,8 @@ export interface StablecoinService {
       pauser?: PublicKey | null;
       blacklister?: PublicKey | null;
       seizer?: PublicKey | null;
+      minterController?: PublicKey | null;
+      allowlister?: PublicKey | null;
     }
   ): Promise<{ signature: string }>;
 }
@@ -0,0 +1,137 @@
+import { describe, it, expect } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import { BN, BorshCoder, utils, type Idl } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { EVENT_IX_TAG_LE } from "@stbr/sss-token";
+import { indexTransaction, legacyEventType } from "../src/events";
+
+const idl = JSON.parse(
+  fs.readFileSync(
+    path.join(__dirname, "../../frontend/public/idl/stablecoin.json"),
+    "utf-8"
+  )
+) as Idl;
+const programId = new PublicKey(idl.address);
+const coder = new BorshCoder(idl);
+
+/** Inner-instruction data `emit_cpi!` produces for `name`. */
+function cpiEventData(name: string, data: Record<string, unknown>): string {
+  const { discriminator } = idl.events!.find((e) => e.name === name)!;
+  return utils.bytes.bs58.encode(
+    Buffer.concat([
+      EVENT_IX_TAG_LE,
+      Buffer.from(discriminator),
+      coder.types.encode(name, data),
+    ])
+  );
+}
+
+/** Minimal fetched transaction whose first instruction CPIs into `programIdIndex` with `data`. */
+function txWithInner(
+  keys: PublicKey[],
+  inner: { programIdIndex: number; data: string }[]
+): any {
+  return {
+    transaction: {
+      message: {
+        getAccountKeys: () => ({ get: (i: number) => keys[i] }),
+      },
+    },
+    meta: {
+      innerInstructions: [
+        {
+          index: 0,
+          instructions: inner.map((ix) => ({ ...ix, accounts: [] })),
+        },
+      ],
+    },
+  };
+}
+
+describe("Indexer event decoding", () => {
+  const mint = Keypair.generate().publicKey;
+  const minter = Keypair.generate().publicKey;
+  const to = Keypair.generate().publicKey;
+
+  it("decodes a Minted CPI event into JSON-safe data", () => {
+    const tx = txWithInner(
+      [minter, programId],
+      [
+        {
+          programIdIndex: 1,
+          data: cpiEventData("Minted", {
+            mint,
+            minter,
+            to,
+            amount: new BN(500_000),
+            daily_minted: new BN(500_000),
+            total_minted: new BN(1_500_000),
+            mint_allowance: new BN(0),
+            supply_before: new BN(1_000_000),
+            supply_after: new BN(1_500_000),
+          }),
+        },
+      ]
+    );
+    const indexed = indexTransaction(coder, programId, tx);
+    expect(indexed.eventType).toBe("mint");
+    expect(indexed.events![0].name).toBe("Minted");
+    expect(indexed.mint).toBe(mint.toBase58());
+    expect(indexed.events).toHaveLength(1);
+    expect(indexed.events![0].data).toMatchObject({
+      minter: minter.toBase58(),
+      to: to.toBase58(),
+      amount: "500000",
+      supply_after: "1500000",
+    });
+  });
+
+  it("ignores inner instructions of other programs and non-event data", () => {
+    const other = Keypair.generate().publicKey;
+    const paused = cpiEventData("Paused", {
+      mint,
+      pauser: minter,
+      previous_flags: 0,
+      pause_flags: 3,
+      reason: { Incident: {} },
+      paused_at: new BN(1_700_000_000),
+    });
+    const tx = txWithInner(
+      [minter, programId, other],
+      [
+        { programIdIndex: 2, data: paused },
+        { programIdIndex: 1, data: utils.bytes.bs58.encode(Buffer.alloc(16)) },
+        { programIdIndex: 1, data: paused },
+      ]
+    );
+    const indexed = indexTransaction(coder, programId, tx);
+    expect(indexed.events!.map((e) => e.name)).toEqual(["Paused"]);
+    // Events the log-based indexer did not classify keep "transaction".
+    expect(indexed.eventType).toBe("transaction");
+    expect(indexed.events![0].data).toMatchObject({
+      pause_flags: 3,
+      paused_at: "1700000000",
+    });
+  });
+
+  it("falls back to a plain transaction when no events are emitted", () => {
+    const indexed = indexTransaction(
+      coder,
+      programId,
+      txWithInner([minter, programId], [])
+    );
+    expect(indexed).toMatchObject({ eventType: "transaction", events: [] });
+    expect(indexed.mint).toBeUndefined();
+  });
+
+  it("maps blacklist changes to the legacy add / remove types", () => {
+    expect(
+      legacyEventType({ name: "Blacklisted", data: { is_blacklisted: true } })
+    ).toBe("blacklist_add");
+    expect(
+      legacyEventType({ name: "Blacklisted", data: { is_blacklisted: false } })
+    ).toBe("blacklist_remove");
+    expect(legacyEventType({ name: "Seized", data: {} })).toBe("seize");
+  });
+});
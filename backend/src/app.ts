@@ -12,8 +12,13 @@ import { getEvents, loadEvents, startIndexer } from "./events";
 import { dispatchWebhook } from "./webhook";
 import { createLogger, log } from "./logger";
 import { screenAddress } from "./screening";
-import { AnchorProvider, Program, Wallet } from "@coral-xyz/anchor";
-import { SolanaStablecoin, SSSComplianceModule } from "@stbr/sss-token";
+import { AnchorProvider, Program, Wallet, type Idl } from "@coral-xyz/anchor";
+import {
+  SolanaStablecoin,
+  SSSComplianceModule,
+  PauseFlags,
+  type BlacklistReasonCode,
+} from "@stbr/sss-token";
 
 declare global {
   namespace Express {
@@ -68,6 +73,25 @@ function loadKeypair(): Keypair {
   return Keypair.fromSecretKey(Uint8Array.from(buf));
 }
 
+/** Path of an Anchor IDL under `<repo>/target/idl`. */
+function idlPath(name: "stablecoin" | "transfer_hook"): string {
+  const repoRoot = process.env.WORKSPACE_ROOT || process.cwd();
+  return path.join(repoRoot, "target", "idl", `${name}.json`);
+}
+
+/** Stablecoin IDL with the configured program ID as its address. */
+function loadStablecoinIdl(): Idl {
+  const stablecoinIdlPath = idlPath("stablecoin");
+  if (!fs.existsSync(stablecoinIdlPath)) {
+    throw new Error(
+      `IDL not found at ${stablecoinIdlPath}. Set WORKSPACE_ROOT or run from repo root.`
+    );
+  }
+  const stablecoinIdl = JSON.parse(fs.readFileSync(stablecoinIdlPath, "utf-8"));
+  (stablecoinIdl as any).address = PROGRAM_IDS.stablecoin;
+  return stablecoinIdl;
+}
+
 function getPrograms(
   connection: Connection,
   wallet: Wallet
@@ -75,30 +99,11 @@ function getPrograms(
   stablecoinProgram: Program<any>;
   transferHookProgram: Program<any> | null;
 } {
-  const repoRoot = process.env.WORKSPACE_ROOT || process.cwd();
-  const stablecoinIdlPath = path.join(
-    repoRoot,
-    "target",
-    "idl",
-    "stablecoin.json"
-  );
-  const transferHookIdlPath = path.join(
-    repoRoot,
-    "target",
-    "idl",
-    "transfer_hook.json"
-  );
-  if (!fs.existsSync(stablecoinIdlPath)) {
-    throw new Error(
-      `IDL not found at ${stablecoinIdlPath}. Set WORKSPACE_ROOT or run from repo root.`
-    );
-  }
+  const transferHookIdlPath = idlPath("transfer_hook");
   const provider = new AnchorProvider(connection, wallet, {
     commitment: "confirmed",
   });
-  const stablecoinIdl = JSON.parse(fs.readFileSync(stablecoinIdlPath, "utf-8"));
-  (stablecoinIdl as any).address = PROGRAM_IDS.stablecoin;
-  const stablecoinProgram = new Program(stablecoinIdl, provider);
+  const stablecoinProgram = new Program(loadStablecoinIdl(), provider);
   let transferHookProgram: Program<any> | null = null;
   if (fs.existsSync(transferHookIdlPath)) {
     const transferHookIdl = JSON.parse(
@@ -220,8 +225,8 @@ app.post("/mint", async (req: Request, res: Response) => {
     const keypair = loadKeypair();
     const sdk = getSdk(mint);
     const config = await sdk.getConfig();
-    if (config.isPaused)
-      return res.status(400).json({ error: "Program is paused" });
+    if (config.pauseFlags & PauseFlags.mint)
+      return res.status(400).json({ error: "Minting is paused" });
     const screening = await screenAddress(sdk, recipientPubkey);
     if (!screening.allowed)
       return res
@@ -268,8 +273,8 @@ app.post("/burn", async (req: Request, res: Response) => {
     const from = fromStr ? new PublicKey(fromStr) : keypair.publicKey;
     const sdk = getSdk(mint);
     const config = await sdk.getConfig();
-    if (config.isPaused)
-      return res.status(400).json({ error: "Program is paused" });
+    if (config.pauseFlags & PauseFlags.burn)
+      return res.status(400).json({ error: "Burning is paused" });
     const screening = await screenAddress(sdk, from);
     if (!screening.allowed)
       return res
@@ -310,7 +315,13 @@ app.post("/blacklist/add", async (req: Request, res: Response) => {
       mint: mintStr,
       address: addressStr,
       reason,
-    } = req.body as { mint?: string; address: string; reason?: string };
+      reasonCode,
+    } = req.body as {
+      mint?: string;
+      address: string;
+      reason?: string;
+      reasonCode?: BlacklistReasonCode;
+    };
     const mint = new PublicKey(mintStr || DEFAULT_MINT);
     const address = new PublicKey(addressStr);
     const keypair = loadKeypair();
@@ -319,13 +330,15 @@ app.post("/blacklist/add", async (req: Request, res: Response) => {
     const tx = await compliance.addToBlacklist(
       keypair.publicKey,
       address,
-      reason
+      reason,
+      reasonCode
     );
     const sig = await tx.rpc();
     logAudit("blacklist_add", {
       mint: mint.toBase58(),
       address: addressStr,
       reason,
+      reasonCode,
       signature: sig,
     });
     const slot = await getSlotForSignature(sig);
@@ -501,7 +514,12 @@ if (process.env.NODE_ENV !== "test") {
       const conn = new Connection(RPC_URL);
       const programId = new PublicKey(PROGRAM_IDS.stablecoin);
       const pollMs = parseInt(process.env.INDEXER_POLL_MS || "8000", 10);
-      startIndexer(conn, programId, isNaN(pollMs) ? 8000 : pollMs);
+      startIndexer(
+        conn,
+        programId,
+        loadStablecoinIdl(),
+        isNaN(pollMs) ? 8000 : pollMs
+      );
       log.info(`Indexer started (poll ${pollMs}ms)`);
     }
   });
@@ -1,17 +1,30 @@
 /**
  * Event store and indexer for stablecoin program transactions.
  * Persists to a JSON file; optional polling of program account for new signatures.
+ * Each transaction is indexed by the typed events the program emits via `emit_cpi!`.
  */
 import * as fs from "fs";
 import * as path from "path";
-import { Connection, PublicKey } from "@solana/web3.js";
+import { BN, BorshCoder, type Idl } from "@coral-xyz/anchor";
+import {
+  Connection,
+  PublicKey,
+  type VersionedTransactionResponse,
+} from "@solana/web3.js";
+import { decodeCpiEvents } from "@stbr/sss-token";
 
 export interface IndexedEvent {
   signature: string;
   slot: number;
   blockTime?: number;
   mint?: string;
+  /**
+   * "mint", "burn", "blacklist_add", "blacklist_remove" or "seize" for the
+   * first event that maps to one (see legacyEventType), else "transaction".
+   */
   eventType?: string;
+  /** Typed events emitted by the stablecoin program, in execution order. */
+  events?: { name: string; data: Record<string, unknown> }[];
 }
 
 const DEFAULT_EVENTS_FILE = "data/events.json";
@@ -86,31 +99,75 @@ export function getEvents(opts: {
   return out.slice(0, limit);
 }
 
-function inferMintFromTx(
-  accountKeys: string[],
-  logMessages: string[]
-): string | undefined {
-  const configPrefix = "config";
-  for (const msg of logMessages) {
-    const m = msg.match(/Program data: (\w+)/);
-    if (m) continue;
-    if (
-      msg.includes("initialize") ||
-      msg.includes("mint") ||
-      msg.includes("Mint")
-    ) {
-      if (accountKeys.length >= 2) return accountKeys[1];
-    }
+/** JSON-safe copy of decoded event data (pubkeys as base58, integers as decimal strings). */
+export function eventDataToJson(value: unknown): unknown {
+  if (value instanceof PublicKey) return value.toBase58();
+  if (BN.isBN(value)) return (value as BN).toString();
+  if (Array.isArray(value)) return value.map(eventDataToJson);
+  if (value && typeof value === "object") {
+    return Object.fromEntries(
+      Object.entries(value).map(([k, v]) => [k, eventDataToJson(v)])
+    );
   }
-  return undefined;
+  return value;
+}
+
+/**
+ * The `eventType` value the log-based indexer stored for an event, so API
+ * consumers and existing rows keep working. Events it did not classify map
+ * to undefined; read `events[].name` for those.
+ */
+export function legacyEventType(ev: {
+  name: string;
+  data: Record<string, unknown>;
+}): string | undefined {
+  switch (ev.name) {
+    case "Minted":
+    case "MintedAtOraclePrice":
+      return "mint";
+    case "Burned":
+      return "burn";
+    case "Blacklisted":
+      return ev.data.is_blacklisted ? "blacklist_add" : "blacklist_remove";
+    case "Seized":
+      return "seize";
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * Decode the stablecoin program's typed CPI events from a fetched transaction.
+ * `mint` comes from the first event (every instruction emits one carrying the
+ * mint); `eventType` from the first event with a legacy type.
+ */
+export function indexTransaction(
+  coder: BorshCoder,
+  programId: PublicKey,
+  tx: VersionedTransactionResponse
+): Pick<IndexedEvent, "mint" | "eventType" | "events"> {
+  const events = decodeCpiEvents(coder, programId, tx).map((ev) => ({
+    name: ev.name,
+    data: eventDataToJson(ev.data) as Record<string, unknown>,
+  }));
+  const first = events[0];
+  return {
+    mint: typeof first?.data.mint === "string" ? first.data.mint : undefined,
+    eventType:
+      events.map(legacyEventType).find((type) => type !== undefined) ??
+      "transaction",
+    events,
+  };
 }
 
 export function startIndexer(
   connection: Connection,
   programId: PublicKey,
+  idl: Idl,
   pollIntervalMs: number = 8000
 ): () => void {
   loadEvents();
+  const coder = new BorshCoder(idl);
   let cancelled = false;
 
   async function poll() {
@@ -122,58 +179,21 @@ export function startIndexer(
       for (let i = sigs.length - 1; i >= 0; i--) {
         const s = sigs[i];
         if (lastSeenSignature && s.signature === lastSeenSignature) continue;
-        let mint: string | undefined;
-        let eventType = "transaction";
+        let indexed: ReturnType<typeof indexTransaction> = {
+          eventType: "transaction",
+        };
         try {
-          const tx = await connection.getParsedTransaction(s.signature, {
+          const tx = await connection.getTransaction(s.signature, {
+            commitment: "confirmed",
             maxSupportedTransactionVersion: 0,
           });
-          if (tx?.meta?.logMessages) {
-            const accountKeys =
-              (tx.transaction as any).message?.accountKeys?.map((k: any) =>
-                typeof k === "string"
-                  ? k
-                  : k.pubkey?.toBase58?.() ?? k.toString()
-              ) ?? [];
-            const keys = accountKeys.length
-              ? accountKeys
-              : (tx.transaction as any).message?.accountKeys ?? [];
-            const keyStrs = Array.isArray(keys)
-              ? keys.map((k: any) =>
-                  typeof k === "string" ? k : k?.toBase58?.() ?? String(k)
-                )
-              : [];
-            mint = inferMintFromTx(keyStrs, tx.meta.logMessages);
-            if (
-              tx.meta.logMessages.some((l) => l.includes("Instruction: Mint"))
-            )
-              eventType = "mint";
-            else if (
-              tx.meta.logMessages.some((l) => l.includes("Instruction: Burn"))
-            )
-              eventType = "burn";
-            else if (
-              tx.meta.logMessages.some((l) =>
-                l.includes("Instruction: AddToBlacklist")
-              )
-            )
-              eventType = "blacklist_add";
-            else if (
-              tx.meta.logMessages.some((l) =>
-                l.includes("Instruction: RemoveFromBlacklist")
-              )
-            )
-              eventType = "blacklist_remove";
-            else if (tx.meta.logMessages.some((l) => l.includes("Seize")))
-              eventType = "seize";
-          }
+          if (tx) indexed = indexTransaction(coder, programId, tx);
         } catch (_) {}
         const ev: IndexedEvent = {
           signature: s.signature,
           slot: s.slot,
           blockTime: s.blockTime ?? undefined,
-          mint,
-          eventType,
+          ...indexed,
         };
         appendEvent(ev);
         lastSeenSignature = s.signature;
@@ -17,6 +17,7 @@ import {
   SSS_1_PRESET,
   SSS_2_PRESET,
   SSS_3_PRESET,
+  PauseFlags,
   type StablecoinConfig,
 } from "@stbr/sss-token";
 
@@ -29,7 +30,7 @@ const PROGRAM_IDS = {
     "4VKhzS8cyVXJPD9VpAopu4g16wzKA6YDm8Wr2TadR7qi",
   oracle:
     process.env.ORACLE_PROGRAM_ID ||
-    "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS",
+    "4xvrXEAm7HKMdgcNehGth4QvRVArJHrfhnrC4gWZfvVu",
 };
 
 function loadKeypair(keypairPath: string): Keypair {
@@ -144,6 +145,27 @@ function getMintFromParent(parent: Command): string {
   return mint;
 }
 
+/** `--expires` value: unix seconds or an ISO-8601 date. */
+function parseExpiry(value?: string): number | undefined {
+  if (value == null) return undefined;
+  const seconds = /^\d+$/.test(value)
+    ? Number(value)
+    : Math.floor(Date.parse(value) / 1000);
+  if (!Number.isFinite(seconds))
+    throw new Error(`Invalid --expires value: ${value}`);
+  return seconds;
+}
+
+/** `--only` value: comma-separated operations (mint,burn,transfer,seize); `fallback` when omitted. */
+function parsePauseFlags(value: string | undefined, fallback: number): number {
+  if (value == null) return fallback;
+  return value.split(",").reduce((flags, op) => {
+    const flag = PauseFlags[op.trim() as keyof typeof PauseFlags];
+    if (flag == null) throw new Error(`Unknown operation for --only: ${op}`);
+    return flags | flag;
+  }, 0);
+}
+
 const program = new Command();
 program
   .name("sss-token")
@@ -172,6 +194,22 @@ program
   .option("-s, --symbol <symbol>", "Token symbol", "SUSD")
   .option("-u, --uri <uri>", "Metadata URI", "https://example.com")
   .option("-d, --decimals <n>", "Decimals", "6")
+  .option(
+    "--auditor <elgamal_pubkey>",
+    "Confidential transfer auditor ElGamal pubkey (base64; SSS-3)"
+  )
+  .option(
+    "--auto-approve",
+    "Let new confidential accounts transact without approval (SSS-3)"
+  )
+  .option(
+    "--supply-elgamal <elgamal_pubkey>",
+    "Enable confidential mint/burn with this supply ElGamal pubkey (base64; SSS-3)"
+  )
+  .option(
+    "--decryptable-supply <ciphertext>",
+    "Zero supply encrypted under the supply AES key (base64; with --supply-elgamal)"
+  )
   .action(async (opts) => {
     const keypair = loadKeypair((program.opts() as any).keypair);
     const connection = getConnection((program.opts() as any).rpcUrl);
@@ -207,6 +245,16 @@ program
         ...preset,
       };
     }
+    if (opts.auditor) config.auditorElGamalPubkey = opts.auditor;
+    if (opts.autoApprove) config.autoApproveConfidentialAccounts = true;
+    if (opts.supplyElgamal) {
+      if (!opts.decryptableSupply)
+        throw new Error("--decryptable-supply is required with --supply-elgamal");
+      config.confidentialMintBurn = {
+        supplyElGamalPubkey: opts.supplyElgamal,
+        decryptableSupply: opts.decryptableSupply,
+      };
+    }
     const sdk = new SolanaStablecoin(
       stablecoinProgram as any,
       undefined,
@@ -226,8 +274,7 @@ program
     if (config.enableTransferHook && transferHookProgram) {
       const compliance = new SSSComplianceModule(sdk);
       const hookTx = await compliance.initializeTransferHookExtraAccounts(
-        keypair.publicKey,
-        config.enableAllowlist ?? false
+        keypair.publicKey
       );
       const hookSig = await hookTx.rpc();
       const minterTx = await sdk.addMinter(
@@ -493,8 +540,17 @@ program
 
 program
   .command("pause")
-  .description("Pause all stablecoin operations")
+  .description(
+    "Pause stablecoin operations (mint, burn and transfer, or only those given)"
+  )
   .requiredOption("-m, --mint <address>", "Mint address")
+  .option("-o, --only <ops>", "Comma-separated: mint,burn,transfer,seize")
+  .option("--seize", "Also pause seizures (left available by default)")
+  .option(
+    "-r, --reason <code>",
+    "Reason code: unspecified, incident, exploit, maintenance, compliance, other",
+    "unspecified"
+  )
   .action(async (opts) => {
     const keypair = loadKeypair((program.opts() as any).keypair);
     const connection = getConnection((program.opts() as any).rpcUrl);
@@ -509,15 +565,21 @@ program
       mint,
       (transferHookProgram || undefined) as any
     );
-    const tx = await sdk.pause(keypair.publicKey);
+    const tx = await sdk.pause(
+      keypair.publicKey,
+      parsePauseFlags(opts.only, PauseFlags.default) |
+        (opts.seize ? PauseFlags.seize : 0),
+      opts.reason
+    );
     const sig = await tx.rpc();
     output({ signature: sig }, (program.opts() as any).json);
   });
 
 program
   .command("unpause")
-  .description("Unpause stablecoin operations")
+  .description("Unpause stablecoin operations (all, or only those given)")
   .requiredOption("-m, --mint <address>", "Mint address")
+  .option("-o, --only <ops>", "Comma-separated: mint,burn,transfer,seize")
   .action(async (opts) => {
     const keypair = loadKeypair((program.opts() as any).keypair);
     const connection = getConnection((program.opts() as any).rpcUrl);
@@ -532,7 +594,10 @@ program
       mint,
       (transferHookProgram || undefined) as any
     );
-    const tx = await sdk.unpause(keypair.publicKey);
+    const tx = await sdk.unpause(
+      keypair.publicKey,
+      parsePauseFlags(opts.only, PauseFlags.all)
+    );
     const sig = await tx.rpc();
     output({ signature: sig }, (program.opts() as any).json);
   });
@@ -557,19 +622,35 @@ program
     );
     const config = await sdk.getConfig();
     const supply = await sdk.getTotalSupply();
+    const headroom = await sdk.getSupplyHeadroom();
     const roles = await sdk.getRoles();
     output(
       {
         mint: mint.toBase58(),
         decimals: config.decimals,
         isPaused: config.isPaused,
+        pauseFlags: config.pauseFlags,
+        pauseReason: config.pauseReason,
+        pausedAt: config.pausedAt,
         supply: supply.toString(),
+        maxSupply: config.maxSupply?.toString() ?? "uncapped",
+        headroom: headroom?.toString() ?? "uncapped",
+        travelRuleThreshold:
+          config.travelRuleThreshold?.toString() ?? "disabled",
+        oracleFeed: config.oracleFeed
+          ? {
+              queue: config.oracleFeed.queue.toBase58(),
+              feedId: Buffer.from(config.oracleFeed.feedId).toString("hex"),
+            }
+          : "disabled",
         masterAuthority: config.masterAuthority.toBase58(),
         roles: {
           burner: roles.burner.toBase58(),
           pauser: roles.pauser.toBase58(),
           blacklister: roles.blacklister.toBase58(),
           seizer: roles.seizer.toBase58(),
+          minterController: roles.minterController.toBase58(),
+          allowlister: roles.allowlister.toBase58(),
         },
       },
       (program.opts() as any).json
@@ -592,6 +673,11 @@ rolesCmd
   .option("--pauser <pubkey>", "Set pauser role to this pubkey")
   .option("--blacklister <pubkey>", "Set blacklister role to this pubkey")
   .option("--seizer <pubkey>", "Set seizer role to this pubkey")
+  .option(
+    "--minter-controller <pubkey>",
+    "Set minter controller role to this pubkey"
+  )
+  .option("--allowlister <pubkey>", "Set allowlister role to this pubkey")
   .action(
     async (
       opts: {
@@ -599,6 +685,8 @@ rolesCmd
         pauser?: string;
         blacklister?: string;
         seizer?: string;
+        minterController?: string;
+        allowlister?: string;
       },
       _cmd,
       cmd
@@ -623,17 +711,23 @@ rolesCmd
         pauser?: PublicKey | null;
         blacklister?: PublicKey | null;
         seizer?: PublicKey | null;
+        minterController?: PublicKey | null;
+        allowlister?: PublicKey | null;
       } = {};
       if (opts.burner != null) roles.burner = new PublicKey(opts.burner);
       if (opts.pauser != null) roles.pauser = new PublicKey(opts.pauser);
       if (opts.blacklister != null)
         roles.blacklister = new PublicKey(opts.blacklister);
       if (opts.seizer != null) roles.seizer = new PublicKey(opts.seizer);
+      if (opts.minterController != null)
+        roles.minterController = new PublicKey(opts.minterController);
+      if (opts.allowlister != null)
+        roles.allowlister = new PublicKey(opts.allowlister);
       if (Object.keys(roles).length === 0) {
         output(
           {
             error:
-              "Provide at least one role: --burner, --pauser, --blacklister, or --seizer",
+              "Provide at least one role: --burner, --pauser, --blacklister, --seizer, --minter-controller, or --allowlister",
           },
           (program.opts() as any).json
         );
@@ -649,6 +743,42 @@ rolesCmd
     }
   );
 
+program
+  .command("migrate")
+  .description(
+    "Upgrade config and roles accounts to the current program layout (master only; safe to re-run)"
+  )
+  .requiredOption("-m, --mint <address>", "Mint address")
+  .option(
+    "--minter <pubkey...>",
+    "Also migrate these minters' configs to the current layout"
+  )
+  .action(async (opts) => {
+    const keypair = loadKeypair((program.opts() as any).keypair);
+    const connection = getConnection((program.opts() as any).rpcUrl);
+    const wallet = new Wallet(keypair);
+    const { stablecoinProgram, transferHookProgram } = loadPrograms(
+      connection,
+      wallet
+    );
+    const sdk = new SolanaStablecoin(
+      stablecoinProgram as any,
+      new PublicKey(opts.mint),
+      (transferHookProgram || undefined) as any
+    );
+    const sig = await sdk.migrateAccounts(keypair.publicKey).rpc();
+    const minters: Record<string, string> = {};
+    for (const minter of (opts.minter ?? []) as string[]) {
+      minters[minter] = await sdk
+        .migrateMinterConfig(keypair.publicKey, new PublicKey(minter))
+        .rpc();
+    }
+    output(
+      opts.minter ? { signature: sig, minters } : { signature: sig },
+      (program.opts() as any).json
+    );
+  });
+
 program
   .command("supply")
   .description("Show total supply")
@@ -668,8 +798,115 @@ program
       (transferHookProgram || undefined) as any
     );
     const supply = await sdk.getTotalSupply();
+    const config = await sdk.getConfig();
+    const headroom = await sdk.getSupplyHeadroom();
+    output(
+      {
+        mint: mint.toBase58(),
+        supply: supply.toString(),
+        maxSupply: config.maxSupply?.toString() ?? "uncapped",
+        headroom: headroom?.toString() ?? "uncapped",
+      },
+      (program.opts() as any).json
+    );
+  });
+
+program
+  .command("set-max-supply <amount>")
+  .description(
+    "Set global supply cap in base units (master authority only); pass 'none' to remove the cap"
+  )
+  .requiredOption("-m, --mint <address>", "Mint address")
+  .action(async (amount, opts) => {
+    const keypair = loadKeypair((program.opts() as any).keypair);
+    const connection = getConnection((program.opts() as any).rpcUrl);
+    const wallet = new Wallet(keypair);
+    const { stablecoinProgram, transferHookProgram } = loadPrograms(
+      connection,
+      wallet
+    );
+    const mint = new PublicKey(opts.mint);
+    const sdk = new SolanaStablecoin(
+      stablecoinProgram as any,
+      mint,
+      (transferHookProgram || undefined) as any
+    );
+    const cap = String(amount).toLowerCase() === "none" ? null : amount;
+    const tx = sdk.setMaxSupply(keypair.publicKey, cap);
+    const sig = await tx.rpc();
+    output(
+      { signature: sig, maxSupply: cap ?? "uncapped" },
+      (program.opts() as any).json
+    );
+  });
+
+program
+  .command("set-travel-rule-threshold <amount>")
+  .description(
+    "Require a travel-rule memo on transfers above this amount in base units (master authority only); pass 'none' to disable"
+  )
+  .requiredOption("-m, --mint <address>", "Mint address")
+  .action(async (amount, opts) => {
+    const keypair = loadKeypair((program.opts() as any).keypair);
+    const connection = getConnection((program.opts() as any).rpcUrl);
+    const wallet = new Wallet(keypair);
+    const { stablecoinProgram, transferHookProgram } = loadPrograms(
+      connection,
+      wallet
+    );
+    const mint = new PublicKey(opts.mint);
+    const sdk = new SolanaStablecoin(
+      stablecoinProgram as any,
+      mint,
+      (transferHookProgram || undefined) as any
+    );
+    const threshold = String(amount).toLowerCase() === "none" ? null : amount;
+    const tx = sdk.setTravelRuleThreshold(keypair.publicKey, threshold);
+    const sig = await tx.rpc();
+    output(
+      { signature: sig, travelRuleThreshold: threshold ?? "disabled" },
+      (program.opts() as any).json
+    );
+  });
+
+program
+  .command("set-oracle-feed [queue] [feedId]")
+  .description(
+    "Pin the Switchboard queue and feed hash (32-byte hex) mint-at-oracle-price accepts (master authority only); pass 'none' to disable"
+  )
+  .requiredOption("-m, --mint <address>", "Mint address")
+  .action(async (queue, feedId, opts) => {
+    const keypair = loadKeypair((program.opts() as any).keypair);
+    const connection = getConnection((program.opts() as any).rpcUrl);
+    const wallet = new Wallet(keypair);
+    const { stablecoinProgram, transferHookProgram } = loadPrograms(
+      connection,
+      wallet
+    );
+    const mint = new PublicKey(opts.mint);
+    const sdk = new SolanaStablecoin(
+      stablecoinProgram as any,
+      mint,
+      (transferHookProgram || undefined) as any
+    );
+    const disable = !queue || String(queue).toLowerCase() === "none";
+    if (!disable && !feedId) {
+      throw new Error("feedId is required unless disabling with 'none'");
+    }
+    const feed = disable
+      ? null
+      : {
+          queue: new PublicKey(queue),
+          feedId: Buffer.from(String(feedId).replace(/^0x/, ""), "hex"),
+        };
+    const sig = await sdk.setOracleFeed(keypair.publicKey, feed).rpc();
     output(
-      { mint: mint.toBase58(), supply: supply.toString() },
+      {
+        signature: sig,
+        oracleFeed: feed
+          ? { queue: feed.queue.toBase58(), feedId: feed.feedId.toString("hex") }
+          : "disabled",
+      },
       (program.opts() as any).json
     );
   });
@@ -681,7 +918,19 @@ program
   .addCommand(
     new Command("add")
       .argument("<address>")
-      .option("-r, --reason <reason>", "Reason")
+      .option(
+        "-r, --reason <reason>",
+        "Reason / case reference text (SHA-256 stored on-chain)"
+      )
+      .option(
+        "-c, --code <code>",
+        "Reason code: unspecified, sanctions, fraud, courtOrder, lawEnforcement, other",
+        "unspecified"
+      )
+      .option(
+        "-e, --expires <time>",
+        "Listing lapses at this time (unix seconds or ISO date)"
+      )
       .action(async (address, opts, parent) => {
         const keypair = loadKeypair((program.opts() as any).keypair);
         const connection = getConnection((program.opts() as any).rpcUrl);
@@ -698,15 +947,19 @@ program
         );
         const compliance = new SSSComplianceModule(sdk);
         const accountPubkey = new PublicKey(address);
+        const expiresAt = parseExpiry(opts.expires);
         let tx = await compliance.addToBlacklist(
           keypair.publicKey,
           accountPubkey,
-          opts.reason
+          opts.reason,
+          opts.code,
+          undefined,
+          expiresAt
         );
         try {
           const sig = await tx.rpc();
           output(
-            { signature: sig, reason: opts.reason },
+            { signature: sig, reason: opts.reason, code: opts.code },
             (program.opts() as any).json
           );
         } catch (e) {
@@ -714,11 +967,19 @@ program
             tx = compliance.updateBlacklistEntry(
               keypair.publicKey,
               accountPubkey,
-              true
+              true,
+              opts.code,
+              opts.reason,
+              expiresAt
             );
             const sig = await tx.rpc();
             output(
-              { signature: sig, updated: true, reason: opts.reason },
+              {
+                signature: sig,
+                updated: true,
+                reason: opts.reason,
+                code: opts.code,
+              },
               (program.opts() as any).json
             );
           } else throw e;
@@ -750,16 +1011,89 @@ program
         const sig = await tx.rpc();
         output({ signature: sig }, (program.opts() as any).json);
       })
+  )
+  .addCommand(
+    new Command("close")
+      .argument("<address>")
+      .option(
+        "--recipient <pubkey>",
+        "Receives the reclaimed rent (default: your keypair)"
+      )
+      .action(async (address, opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const compliance = new SSSComplianceModule(sdk);
+        const tx = compliance.closeBlacklistEntry(
+          keypair.publicKey,
+          new PublicKey(address),
+          opts.recipient ? new PublicKey(opts.recipient) : keypair.publicKey
+        );
+        const sig = await tx.rpc();
+        output({ signature: sig }, (program.opts() as any).json);
+      })
   );
 
+/**
+ * `allowlist enable|disable`: flip config.enable_allowlist and rebuild the
+ * hook's extra-account-metas in the same transaction (master authority).
+ */
+function setAllowlistEnabledCommand(name: string, enabled: boolean): Command {
+  return new Command(name)
+    .description(
+      `${enabled ? "Enforce" : "Stop enforcing"} the allowlist in the transfer hook (master only)`
+    )
+    .action(async (_opts, parent) => {
+      const keypair = loadKeypair((program.opts() as any).keypair);
+      const connection = getConnection((program.opts() as any).rpcUrl);
+      const wallet = new Wallet(keypair);
+      const { stablecoinProgram, transferHookProgram } = loadPrograms(
+        connection,
+        wallet
+      );
+      if (!transferHookProgram)
+        throw new Error("Transfer hook program required");
+      const mint = new PublicKey(getMintFromParent(parent));
+      const sdk = new SolanaStablecoin(
+        stablecoinProgram as any,
+        mint,
+        transferHookProgram as any
+      );
+      const compliance = new SSSComplianceModule(sdk);
+      const updateHookIx = await compliance
+        .updateTransferHookExtraAccounts(keypair.publicKey)
+        .instruction();
+      const sig = await sdk
+        .setAllowlistEnabled(keypair.publicKey, enabled)
+        .postInstructions([updateHookIx])
+        .rpc();
+      output({ signature: sig, enabled }, (program.opts() as any).json);
+    });
+}
+
 program
   .command("allowlist")
-  .description("SSS-3 allowlist commands (master authority only)")
+  .description("SSS-3 allowlist commands (allowlister or master authority)")
   .requiredOption("-m, --mint <address>", "Mint address")
   .addCommand(
     new Command("add")
       .argument("<wallet>")
-      .action(async (walletAddress, _opts, parent) => {
+      .option(
+        "-e, --expires <time>",
+        "Approval lapses at this time (unix seconds or ISO date)"
+      )
+      .option("-t, --tier <tier>", "Allowlist policy tier", "0")
+      .action(async (walletAddress, opts, parent) => {
         const keypair = loadKeypair((program.opts() as any).keypair);
         const connection = getConnection((program.opts() as any).rpcUrl);
         const wallet = new Wallet(keypair);
@@ -774,9 +1108,13 @@ program
           (transferHookProgram || undefined) as any
         );
         const walletPubkey = new PublicKey(walletAddress);
+        const expiresAt = parseExpiry(opts.expires);
+        const tier = parseInt(opts.tier, 10);
         let tx = await sdk.addToAllowlist(
           keypair.publicKey,
-          walletPubkey
+          walletPubkey,
+          expiresAt,
+          tier
         );
         try {
           const sig = await tx.rpc();
@@ -789,7 +1127,9 @@ program
             tx = sdk.updateAllowlistEntry(
               keypair.publicKey,
               walletPubkey,
-              true
+              true,
+              expiresAt,
+              tier
             );
             const sig = await tx.rpc();
             output(
@@ -827,6 +1167,448 @@ program
           (program.opts() as any).json
         );
       })
+  )
+  .addCommand(
+    new Command("close")
+      .argument("<wallet>")
+      .option(
+        "--recipient <pubkey>",
+        "Receives the reclaimed rent (default: your keypair)"
+      )
+      .action(async (walletAddress, opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const tx = sdk.closeAllowlistEntry(
+          keypair.publicKey,
+          new PublicKey(walletAddress),
+          opts.recipient ? new PublicKey(opts.recipient) : keypair.publicKey
+        );
+        const sig = await tx.rpc();
+        output(
+          { signature: sig, wallet: walletAddress },
+          (program.opts() as any).json
+        );
+      })
+  
+  )
+  .addCommand(
+    new Command("tier")
+      .description(
+        "Set a policy tier's per-transfer cap (base units, 0 = no cap) and the tiers it may send to (master only)"
+      )
+      .argument("<tier>")
+      .argument("<max_amount>")
+      .argument("<recipient_tiers>", "Comma-separated tiers, e.g. 0,2")
+      .action(async (tier, maxAmount, recipientTiers, _opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const policy = SolanaStablecoin.getAllowlistPolicyPDA(
+          mint,
+          stablecoinProgram.programId
+        );
+        const tx = sdk.setAllowlistTier(
+          keypair.publicKey,
+          parseInt(tier, 10),
+          maxAmount,
+          recipientTiers
+            .split(",")
+            .filter((t: string) => t.trim() !== "")
+            .map((t: string) => parseInt(t, 10))
+        );
+        if ((await connection.getAccountInfo(policy)) == null) {
+          tx.preInstructions([
+            await sdk.initializeAllowlistPolicy(keypair.publicKey).instruction(),
+          ]);
+        }
+        const sig = await tx.rpc();
+        output(
+          { signature: sig, tier, maxAmount, recipientTiers },
+          (program.opts() as any).json
+        );
+      })
+  )
+  .addCommand(setAllowlistEnabledCommand("enable", true))
+  .addCommand(setAllowlistEnabledCommand("disable", false));
+
+program
+  .command("transfer-limit")
+  .description("Outbound transfer caps enforced by the transfer hook (master authority)")
+  .requiredOption("-m, --mint <address>", "Mint address")
+  .addCommand(
+    new Command("tier")
+      .description("Set a config tier's caps in base units (0 = no cap)")
+      .argument("<tier>")
+      .argument("<daily>")
+      .argument("<per_transfer>")
+      .action(async (tier, daily, perTransfer, _opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const sig = await sdk
+          .setTransferLimitTier(
+            keypair.publicKey,
+            parseInt(tier, 10),
+            daily,
+            perTransfer
+          )
+          .rpc();
+        output(
+          { signature: sig, tier, daily, perTransfer },
+          (program.opts() as any).json
+        );
+      })
+  )
+  .addCommand(
+    new Command("set")
+      .description("Assign a wallet to a tier, optionally overriding its caps")
+      .argument("<wallet>")
+      .argument("<tier>")
+      .option("-d, --daily <amount>", "Daily cap override (base units)")
+      .option(
+        "-p, --per-transfer <amount>",
+        "Per-transfer cap override (base units)"
+      )
+      .action(async (walletAddress, tier, opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        if (!transferHookProgram)
+          throw new Error("Transfer hook program required for transfer limits");
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          transferHookProgram as any
+        );
+        const compliance = new SSSComplianceModule(sdk);
+        const walletPubkey = new PublicKey(walletAddress);
+        const args = [
+          keypair.publicKey,
+          walletPubkey,
+          parseInt(tier, 10),
+          opts.daily ?? null,
+          opts.perTransfer ?? null,
+        ] as const;
+        try {
+          const sig = await compliance.initializeTransferLimit(...args).rpc();
+          output(
+            { signature: sig, wallet: walletAddress, tier },
+            (program.opts() as any).json
+          );
+        } catch (e) {
+          if (!isAlreadyInitializedError(e)) throw e;
+          const sig = await compliance.updateTransferLimit(...args).rpc();
+          output(
+            { signature: sig, updated: true, wallet: walletAddress, tier },
+            (program.opts() as any).json
+          );
+        }
+      })
+  )
+  .addCommand(
+    new Command("close")
+      .description("Remove a wallet's caps and reclaim the account rent")
+      .argument("<wallet>")
+      .action(async (walletAddress, _opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        if (!transferHookProgram)
+          throw new Error("Transfer hook program required for transfer limits");
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          transferHookProgram as any
+        );
+        const sig = await new SSSComplianceModule(sdk)
+          .closeTransferLimit(keypair.publicKey, new PublicKey(walletAddress))
+          .rpc();
+        output(
+          { signature: sig, closed: true, wallet: walletAddress },
+          (program.opts() as any).json
+        );
+      })
+  );
+
+program
+  .command("confidential")
+  .description("SSS-3 confidential transfer settings")
+  .requiredOption("-m, --mint <address>", "Mint address")
+  .addCommand(
+    new Command("approve")
+      .description(
+        "Approve a wallet's configured confidential account (allowlister or master)"
+      )
+      .argument("<owner>")
+      .option("--account <address>", "Token account (default: owner's ATA)")
+      .action(async (owner, opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const sig = await sdk
+          .getConfidential()
+          .approveAccount(
+            keypair.publicKey,
+            new PublicKey(owner),
+            opts.account ? new PublicKey(opts.account) : undefined
+          )
+          .rpc();
+        output({ signature: sig, owner }, (program.opts() as any).json);
+      })
+  )
+  .addCommand(
+    new Command("update-mint")
+      .description(
+        "Set auto-approval and rotate or remove the auditor key (master only)"
+      )
+      .option(
+        "--auto-approve",
+        "Approve new confidential accounts automatically"
+      )
+      .option(
+        "--auditor <elgamal_pubkey>",
+        "Auditor ElGamal pubkey (base64); omit to remove the auditor"
+      )
+      .action(async (opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const sig = await sdk
+          .getConfidential()
+          .updateMint(
+            keypair.publicKey,
+            !!opts.autoApprove,
+            opts.auditor ?? null
+          )
+          .rpc();
+        output(
+          {
+            signature: sig,
+            autoApprove: !!opts.autoApprove,
+            auditor: opts.auditor ?? null,
+          },
+          (program.opts() as any).json
+        );
+      })
+  )
+  .addCommand(
+    new Command("reconcile-supply")
+      .description(
+        "Set the tracked confidential supply to the decrypted on-mint value (master only)"
+      )
+      .argument("<amount>", "Confidential supply in base units")
+      .action(async (amount, _opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const sig = await sdk
+          .getConfidential()
+          .reconcileSupply(keypair.publicKey, amount)
+          .rpc();
+        output(
+          { signature: sig, confidentialSupply: amount },
+          (program.opts() as any).json
+        );
+      })
+  )
+  .addCommand(
+    new Command("mint")
+      .description(
+        "Mint into a pending confidential balance using pre-verified proofs (minter)"
+      )
+      .argument("<to>")
+      .argument("<amount>")
+      .requiredOption(
+        "--new-decryptable-supply <ciphertext>",
+        "Supply after the mint, encrypted under the supply AES key (base64)"
+      )
+      .requiredOption(
+        "--equality-proof <address>",
+        "CiphertextCommitmentEquality proof context account"
+      )
+      .requiredOption(
+        "--validity-proof <address>",
+        "BatchedGroupedCiphertext3HandlesValidity proof context account"
+      )
+      .requiredOption(
+        "--range-proof <address>",
+        "BatchedRangeProofU128 proof context account"
+      )
+      .requiredOption(
+        "--opening-lo <opening>",
+        "Pedersen opening of the low amount commitment (base64)"
+      )
+      .requiredOption(
+        "--opening-hi <opening>",
+        "Pedersen opening of the high amount commitment (base64)"
+      )
+      .option("--account <address>", "Token account (default: recipient's ATA)")
+      .action(async (to, amount, opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const sig = await sdk
+          .getConfidential()
+          .confidentialMint(
+            keypair.publicKey,
+            new PublicKey(to),
+            amount,
+            opts.newDecryptableSupply,
+          {
+            equality: new PublicKey(opts.equalityProof),
+            ciphertextValidity: new PublicKey(opts.validityProof),
+            range: new PublicKey(opts.rangeProof),
+            amountOpening: { lo: opts.openingLo, hi: opts.openingHi },
+          },
+            opts.account ? new PublicKey(opts.account) : undefined
+          )
+          .rpc();
+        output({ signature: sig, to, amount }, (program.opts() as any).json);
+      })
+  )
+  .addCommand(
+    new Command("burn")
+      .description(
+        "Burn from your confidential available balance using pre-verified proofs (burner)"
+      )
+      .argument("<amount>")
+      .requiredOption(
+        "--new-decryptable-balance <ciphertext>",
+        "Available balance after the burn, encrypted under your AES key (base64)"
+      )
+      .requiredOption(
+        "--equality-proof <address>",
+        "CiphertextCommitmentEquality proof context account"
+      )
+      .requiredOption(
+        "--validity-proof <address>",
+        "BatchedGroupedCiphertext3HandlesValidity proof context account"
+      )
+      .requiredOption(
+        "--range-proof <address>",
+        "BatchedRangeProofU128 proof context account"
+      )
+      .requiredOption(
+        "--opening-lo <opening>",
+        "Pedersen opening of the low amount commitment (base64)"
+      )
+      .requiredOption(
+        "--opening-hi <opening>",
+        "Pedersen opening of the high amount commitment (base64)"
+      )
+      .option("--account <address>", "Token account (default: your ATA)")
+      .action(async (amount, opts, parent) => {
+        const keypair = loadKeypair((program.opts() as any).keypair);
+        const connection = getConnection((program.opts() as any).rpcUrl);
+        const wallet = new Wallet(keypair);
+        const { stablecoinProgram, transferHookProgram } = loadPrograms(
+          connection,
+          wallet
+        );
+        const mint = new PublicKey(getMintFromParent(parent));
+        const sdk = new SolanaStablecoin(
+          stablecoinProgram as any,
+          mint,
+          (transferHookProgram || undefined) as any
+        );
+        const sig = await sdk
+          .getConfidential()
+          .confidentialBurn(
+            keypair.publicKey,
+            amount,
+            opts.newDecryptableBalance,
+          {
+            equality: new PublicKey(opts.equalityProof),
+            ciphertextValidity: new PublicKey(opts.validityProof),
+            range: new PublicKey(opts.rangeProof),
+            amountOpening: { lo: opts.openingLo, hi: opts.openingHi },
+          },
+            opts.account ? new PublicKey(opts.account) : undefined
+          )
+          .rpc();
+        output({ signature: sig, amount }, (program.opts() as any).json);
+      })
   );
 
 program
@@ -885,7 +1667,15 @@ mintersCmd
 mintersCmd
   .command("add <minter_pubkey>")
   .description("Add a minter with quota")
-  .option("-q, --quota <quota>", "Daily mint quota", "1000000000")
+  .option("-q, --quota <quota>", "Mint quota per rolling window", "1000000000")
+  .option(
+    "-w, --window <seconds>",
+    "Rolling quota window in seconds, a multiple of 24 (default 86400)"
+  )
+  .option(
+    "--allowance-mode",
+    "Limit by a decrementing allowance (see minters allowance) instead of a rolling quota"
+  )
   .action(async (minterPubkey: string, opts: any, cmd?: Command) => {
     const parent = cmd?.parent as Command | undefined;
     const mint = parent?.opts?.()?.mint ?? (mintersCmd as any).opts?.()?.mint;
@@ -902,11 +1692,15 @@ mintersCmd
       (transferHookProgram || undefined) as any
     );
     const minterKey = new PublicKey(minterPubkey);
+    const windowSeconds = opts.window ? parseInt(opts.window, 10) : null;
+    const quotaMode = opts.allowanceMode ? "allowance" : "rollingWindow";
     let tx = await sdk.addMinter(
       keypair.publicKey,
       minterKey,
       true,
-      opts.quota
+      opts.quota,
+      windowSeconds,
+      quotaMode
     );
     try {
       const sig = await tx.rpc();
@@ -920,7 +1714,9 @@ mintersCmd
           keypair.publicKey,
           minterKey,
           true,
-          opts.quota
+          opts.quota,
+          windowSeconds,
+          quotaMode
         );
         const sig = await tx.rpc();
         output(
@@ -930,6 +1726,44 @@ mintersCmd
       } else throw e;
     }
   });
+mintersCmd
+  .command("allowance <minter_pubkey> <amount>")
+  .description(
+    "Increase (or with --decrease, reduce) an allowance-mode minter's allowance (minter controller or master)"
+  )
+  .option("--decrease", "Reduce the allowance instead (clamped at zero)")
+  .action(
+    async (minterPubkey: string, amount: string, opts: any, cmd?: Command) => {
+      const parent = cmd?.parent as Command | undefined;
+      const mint =
+        parent?.opts?.()?.mint ?? (mintersCmd as any).opts?.()?.mint;
+      const keypair = loadKeypair((program.opts() as any).keypair);
+      const connection = getConnection((program.opts() as any).rpcUrl);
+      const wallet = new Wallet(keypair);
+      const { stablecoinProgram, transferHookProgram } = loadPrograms(
+        connection,
+        wallet
+      );
+      const sdk = new SolanaStablecoin(
+        stablecoinProgram as any,
+        new PublicKey(mint),
+        (transferHookProgram || undefined) as any
+      );
+      const minterKey = new PublicKey(minterPubkey);
+      const tx = opts.decrease
+        ? sdk.decreaseMinterAllowance(keypair.publicKey, minterKey, amount)
+        : sdk.increaseMinterAllowance(keypair.publicKey, minterKey, amount);
+      const sig = await tx.rpc();
+      output(
+        {
+          signature: sig,
+          minter: minterPubkey,
+          [opts.decrease ? "decreasedBy" : "increasedBy"]: amount,
+        },
+        (program.opts() as any).json
+      );
+    }
+  );
 mintersCmd
   .command("remove <minter_pubkey>")
   .description("Remove / deactivate a minter")
@@ -961,6 +1795,42 @@ mintersCmd
     );
   });
 
+mintersCmd
+  .command("close <minter_pubkey>")
+  .description(
+    "Retire a minter: close its config account and reclaim the rent (master only)"
+  )
+  .option(
+    "--recipient <pubkey>",
+    "Receives the reclaimed rent (default: your keypair)"
+  )
+  .action(async (minterPubkey: string, opts: any, cmd?: Command) => {
+    const parent = cmd?.parent as Command | undefined;
+    const mint = parent?.opts?.()?.mint ?? (mintersCmd as any).opts?.()?.mint;
+    const keypair = loadKeypair((program.opts() as any).keypair);
+    const connection = getConnection((program.opts() as any).rpcUrl);
+    const wallet = new Wallet(keypair);
+    const { stablecoinProgram, transferHookProgram } = loadPrograms(
+      connection,
+      wallet
+    );
+    const sdk = new SolanaStablecoin(
+      stablecoinProgram as any,
+      new PublicKey(mint),
+      (transferHookProgram || undefined) as any
+    );
+    const tx = sdk.removeMinter(
+      keypair.publicKey,
+      new PublicKey(minterPubkey),
+      opts.recipient ? new PublicKey(opts.recipient) : keypair.publicKey
+    );
+    const sig = await tx.rpc();
+    output(
+      { signature: sig, minter: minterPubkey, closed: true },
+      (program.opts() as any).json
+    );
+  });
+
 program
   .command("holders")
   .description("List holders by balance (uses RPC getTokenLargestAccounts)")
@@ -1006,7 +1876,7 @@ program
     output(
       {
         message:
-          "Oracle helpers are available via the SDK (computeMintAmountFromOracle) and program IDL; see docs/ORACLE.md for full examples.",
+          "Oracle helpers are available via the SDK (computeMintAmountFromOracle, mintAtOraclePrice) and program IDL; see docs/ORACLE.md for full examples.",
       },
       (program.opts() as any).json
     );
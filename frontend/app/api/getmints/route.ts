@@ -31,7 +31,7 @@ function decodeStablecoinConfig(data: Buffer | Uint8Array): {
   readString(); // uri
   const decimals = buf.readUInt8(o);
   o += 1;
-  const isPaused = buf.readUInt8(o) !== 0;
+  const isPaused = buf.readUInt8(o) !== 0; // pause_flags: any operation paused
   o += 1;
   o += 1; // enable_permanent_delegate
   o += 1; // enable_transfer_hook
@@ -72,15 +72,16 @@ export function OracleMintSection() {
         return;
       }
 
+      const feedHashBytes = feedHash.length === 64 && /^[0-9a-fA-F]+$/.test(feedHash)
+        ? Buffer.from(feedHash, "hex")
+        : Buffer.from(feedHash, "utf-8");
+
       // Fetch Switchboard update + verify instructions so that verify is at index 1.
       // Use @switchboard-xyz/on-demand: fetchUpdateBundleIx or equivalent; ensure verify is at index 1.
       let preInstructions: import("@solana/web3.js").TransactionInstruction[] = [];
       if (feedHash) {
         try {
           const onDemand = await import("@switchboard-xyz/on-demand");
-          const feedHashBytes = feedHash.length === 64 && /^[0-9a-fA-F]+$/.test(feedHash)
-            ? Buffer.from(feedHash, "hex")
-            : Buffer.from(feedHash, "utf-8");
           const fn = (onDemand as any).fetchUpdateBundleIx ?? (onDemand as any).default?.fetchUpdateBundleIx;
           if (typeof fn === "function") {
             const bundle = await fn(connection, queuePubkey, [feedHashBytes], { payer: walletPubkey });
@@ -102,6 +103,7 @@ export function OracleMintSection() {
         instructionsSysvar,
         pegAmount: pegAmountSmallest,
         tokenDecimals: decimals,
+        feedId: feedHashBytes,
         preInstructions,
       });
 
@@ -15,6 +15,8 @@ const ROLES = [
   { key: "pauser" as const, label: "Pauser", description: "Can pause / unpause mint" },
   { key: "blacklister" as const, label: "Blacklister", description: "Can blacklist addresses" },
   { key: "seizer" as const, label: "Seizer", description: "Can seize tokens" },
+  { key: "minterController" as const, label: "Minter controller", description: "Can raise / lower minter allowances" },
+  { key: "allowlister" as const, label: "Allowlister", description: "Can add / remove allowlist entries" },
 ] as const;
 
 function shortPubkey(pubkey: PublicKey): string {
@@ -23,7 +25,7 @@ function shortPubkey(pubkey: PublicKey): string {
 }
 
 /**
- * Assign or revoke roles (burner, pauser, blacklister, seizer).
+ * Assign or revoke roles (burner, pauser, blacklister, seizer, minter controller, allowlister).
  * Only master authority can call update_roles.
  */
 export function RoleManagement() {
@@ -130,7 +132,7 @@ export function RoleManagement() {
         <p className="font-semibold text-foreground">Role management</p>
         <p className="text-sm text-muted">
           {isAuthority
-            ? "Assign or revoke burner, pauser, blacklister, and seizer. Only master authority can update roles."
+            ? "Assign or revoke burner, pauser, blacklister, seizer, minter controller, and allowlister. Only master authority can update roles."
             : "Only the master authority can update roles. Connect the wallet that created this stablecoin."}
         </p>
       </div>
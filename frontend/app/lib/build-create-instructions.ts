@@ -64,8 +64,7 @@ export async function buildCreateStablecoinInstructions(
     const { SSSComplianceModule } = await import("@stbr/sss-token");
     const compliance = new SSSComplianceModule(instance as any);
     const hookBuilder = await compliance.initializeTransferHookExtraAccounts(
-      authorityPublicKey,
-      (config as any).enableAllowlist ?? false
+      authorityPublicKey
     );
     const hookIx = await builderToInstruction(hookBuilder);
     instructions.push(hookIx);
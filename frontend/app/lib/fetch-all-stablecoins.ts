@@ -70,7 +70,7 @@ export async function fetchAllStablecoinConfigs(
         name: string;
         symbol: string;
         decimals: number;
-        is_paused?: boolean;
+        pause_flags?: number;
         enable_allowlist?: boolean;
         enable_confidential_transfers?: boolean;
       };
@@ -81,7 +81,9 @@ export async function fetchAllStablecoinConfigs(
         name: decoded.name ?? "",
         symbol: decoded.symbol ?? "",
         decimals: Number(decoded.decimals ?? 0),
-        isPaused: Boolean(decoded.is_paused ?? (decoded as any).isPaused),
+        isPaused: Boolean(
+          decoded.pause_flags ?? (decoded as any).pauseFlags
+        ),
         enableAllowlist: Boolean(
           decoded.enable_allowlist ?? (decoded as any).enableAllowlist
         ),
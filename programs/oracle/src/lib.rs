@@ -10,7 +10,7 @@ use anchor_lang::prelude::*;
 use switchboard_on_demand::prelude::rust_decimal::prelude::{Decimal, ToPrimitive};
 use switchboard_on_demand::QuoteVerifier;
 
-declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+declare_id!("4xvrXEAm7HKMdgcNehGth4QvRVArJHrfhnrC4gWZfvVu");
 
 #[program]
 pub mod oracle {
@@ -34,24 +34,45 @@ pub mod oracle {
         Ok(())
     }
 
-    /// Compute token amount to mint for a given peg amount (e.g. 100 EUR).
-    /// Returns amount via set_return_data (u64 little-endian).
+    /// Compute token amount to mint for a given peg amount (e.g. 100 EUR), priced by the
+    /// quote's first feed. Returns amount via set_return_data (u64 little-endian).
     pub fn compute_mint_amount(
         ctx: Context<ReadQuote>,
         peg_amount: u64,
         token_decimals: u8,
     ) -> Result<()> {
-        compute_amount_impl(ctx, peg_amount, token_decimals)
+        compute_amount_impl(ctx, peg_amount, token_decimals, None)
     }
 
-    /// Compute token amount to redeem (burn) for a given peg amount.
+    /// Compute token amount to redeem (burn) for a given peg amount, priced by the first feed.
     /// Returns amount via set_return_data (u64 little-endian).
     pub fn compute_redeem_amount(
         ctx: Context<ReadQuote>,
         peg_amount: u64,
         token_decimals: u8,
     ) -> Result<()> {
-        compute_amount_impl(ctx, peg_amount, token_decimals)
+        compute_amount_impl(ctx, peg_amount, token_decimals, None)
+    }
+
+    /// Same as `compute_mint_amount`, but priced by the quote's `feed_id` feed, which may sit
+    /// anywhere in a multi-feed quote.
+    pub fn compute_mint_amount_for_feed(
+        ctx: Context<ReadQuote>,
+        peg_amount: u64,
+        token_decimals: u8,
+        feed_id: [u8; 32],
+    ) -> Result<()> {
+        compute_amount_impl(ctx, peg_amount, token_decimals, Some(&feed_id))
+    }
+
+    /// Same as `compute_redeem_amount`, but priced by the quote's `feed_id` feed.
+    pub fn compute_redeem_amount_for_feed(
+        ctx: Context<ReadQuote>,
+        peg_amount: u64,
+        token_decimals: u8,
+        feed_id: [u8; 32],
+    ) -> Result<()> {
+        compute_amount_impl(ctx, peg_amount, token_decimals, Some(&feed_id))
     }
 }
 
@@ -74,7 +95,14 @@ pub struct ReadQuote<'info> {
 
 /// Client must send tx with instructions: [Switchboard update ix at 0, Ed25519 verify ix at 1, this program ix at 2].
 /// Uses fixed-point Decimal math only (no f64) for financial safety.
-fn compute_amount_impl(ctx: Context<ReadQuote>, peg_amount: u64, token_decimals: u8) -> Result<()> {
+/// The price is read from the `feed_id` entry when given (a quote may carry several feeds in
+/// any order), otherwise from the first feed.
+fn compute_amount_impl(
+    ctx: Context<ReadQuote>,
+    peg_amount: u64,
+    token_decimals: u8,
+    feed_id: Option<&[u8; 32]>,
+) -> Result<()> {
     require!(peg_amount > 0, OracleError::ZeroPrice);
     require!(token_decimals <= 18, OracleError::Overflow);
 
@@ -88,8 +116,12 @@ fn compute_amount_impl(ctx: Context<ReadQuote>, peg_amount: u64, token_decimals:
         .verify_instruction_at(1)
         .map_err(|_| error!(OracleError::InvalidFeed))?;
 
-    let feeds = quote.feeds();
-    let feed = feeds.first().ok_or(OracleError::InvalidFeed)?;
+    let feed = match feed_id {
+        Some(feed_id) => quote
+            .feed(feed_id)
+            .map_err(|_| error!(OracleError::InvalidFeed))?,
+        None => quote.feeds().first().ok_or(OracleError::InvalidFeed)?,
+    };
     let price_decimal = feed.value();
     if price_decimal.is_zero() {
         return err!(OracleError::ZeroPrice);
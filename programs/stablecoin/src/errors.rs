@@ -24,4 +24,38 @@ pub enum StablecoinError {
     InvalidDecimals,
     #[msg("Amount must be greater than zero")]
     InvalidAmount,
+    #[msg("No authority transfer is pending for this role")]
+    NoPendingAuthority,
+    #[msg("DefaultAccountState extension is not enabled for this mint")]
+    DefaultAccountStateNotEnabled,
+    #[msg("Mint would exceed the configured max supply")]
+    SupplyCapExceeded,
+    #[msg("Mint window must be a positive multiple of 24 seconds")]
+    InvalidMintWindow,
+    #[msg("Minter has exceeded their remaining mint allowance")]
+    AllowanceExceeded,
+    #[msg("Account data does not match any known layout")]
+    UnknownAccountLayout,
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("Pause mask must be non-zero and only contain known flags")]
+    InvalidPauseFlags,
+    #[msg("Transfer limit tier is out of range")]
+    InvalidTransferLimitTier,
+    #[msg("Allowlist tier is out of range")]
+    InvalidAllowlistTier,
+    #[msg("Auditor ElGamal pubkey must not be all zeros")]
+    InvalidAuditorElGamalPubkey,
+    #[msg("Confidential mint and burn are not enabled for this stablecoin")]
+    ConfidentialMintBurnNotEnabled,
+    #[msg("Amount opening does not match the ciphertext validity proof")]
+    ConfidentialAmountMismatch,
+    #[msg("Oracle program did not return a token amount")]
+    InvalidOracleReturnData,
+    #[msg("No oracle feed is configured for this stablecoin")]
+    OracleFeedNotSet,
+    #[msg("Queue does not match the configured oracle feed")]
+    InvalidOracleQueue,
+    #[msg("Public mint is disabled while confidential mint and burn are enabled; use confidential_mint")]
+    PublicMintDisabled,
 }
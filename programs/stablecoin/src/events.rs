@@ -0,0 +1,369 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    AllowlistTier, AuthorityRole, BlacklistReason, MintQuotaMode, OracleFeed, PauseReason,
+    TransferLimitTier,
+};
+
+/// Role assignments at a point in time (used for before/after in RolesUpdated).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoleSnapshot {
+    pub burner: Pubkey,
+    pub pauser: Pubkey,
+    pub blacklister: Pubkey,
+    pub seizer: Pubkey,
+    pub minter_controller: Pubkey,
+    pub allowlister: Pubkey,
+}
+
+#[event]
+pub struct StablecoinInitialized {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub decimals: u8,
+    pub enable_permanent_delegate: bool,
+    pub enable_transfer_hook: bool,
+    pub default_account_frozen: bool,
+    pub enable_confidential_transfers: bool,
+    pub enable_allowlist: bool,
+    pub auto_approve_confidential_accounts: bool,
+    pub auditor_elgamal_pubkey: Option<[u8; 32]>,
+    pub enable_confidential_mint_burn: bool,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct Minted {
+    pub mint: Pubkey,
+    pub minter: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub daily_minted: u64,
+    pub total_minted: u64,
+    pub mint_allowance: u64,
+    pub supply_before: u64,
+    pub supply_after: u64,
+}
+
+#[event]
+pub struct MintedAtOraclePrice {
+    pub mint: Pubkey,
+    pub minter: Pubkey,
+    pub to: Pubkey,
+    pub peg_amount: u64, // Peg units priced by the oracle program
+    pub amount: u64,     // Token amount it returned and that was minted
+}
+
+#[event]
+pub struct Burned {
+    pub mint: Pubkey,
+    pub burner: Pubkey,
+    pub from: Pubkey,
+    pub amount: u64,
+    pub supply_before: u64,
+    pub supply_after: u64,
+}
+
+#[event]
+pub struct Paused {
+    pub mint: Pubkey,
+    pub pauser: Pubkey,
+    pub previous_flags: u8,
+    pub pause_flags: u8,
+    pub reason: PauseReason,
+    pub paused_at: i64,
+}
+
+#[event]
+pub struct Unpaused {
+    pub mint: Pubkey,
+    pub pauser: Pubkey,
+    pub previous_flags: u8,
+    pub pause_flags: u8,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub mint: Pubkey,
+    pub role: AuthorityRole,
+    pub current_authority: Pubkey,
+    pub proposed_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub mint: Pubkey,
+    pub role: AuthorityRole,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub mint: Pubkey,
+    pub role: AuthorityRole,
+    pub cancelled_authority: Pubkey,
+}
+
+#[event]
+pub struct AccountsMigrated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub config_migrated: bool,
+    pub roles_migrated: bool,
+}
+
+#[event]
+pub struct MinterConfigMigrated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+}
+
+#[event]
+pub struct MaxSupplyUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous_max_supply: Option<u64>,
+    pub max_supply: Option<u64>,
+}
+
+#[event]
+pub struct TravelRuleThresholdUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous_threshold: Option<u64>,
+    pub threshold: Option<u64>,
+}
+
+#[event]
+pub struct OracleFeedUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous_feed: Option<OracleFeed>,
+    pub feed: Option<OracleFeed>,
+}
+
+#[event]
+pub struct TransferLimitTierUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub tier: u8,
+    pub previous_limits: TransferLimitTier,
+    pub limits: TransferLimitTier,
+}
+
+#[event]
+pub struct RolesUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous: RoleSnapshot,
+    pub current: RoleSnapshot,
+}
+
+#[event]
+pub struct MinterUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub previous_is_active: bool,
+    pub is_active: bool,
+    pub previous_daily_mint_quota: u64,
+    pub daily_mint_quota: u64,
+    pub previous_window_seconds: u32,
+    pub window_seconds: u32,
+    pub previous_quota_mode: MintQuotaMode,
+    pub quota_mode: MintQuotaMode,
+}
+
+#[event]
+pub struct MinterAllowanceUpdated {
+    pub mint: Pubkey,
+    pub controller: Pubkey,
+    pub minter: Pubkey,
+    pub previous_allowance: u64,
+    pub allowance: u64,
+}
+
+#[event]
+pub struct MinterRemoved {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub minter: Pubkey,
+    pub total_minted: u64,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct ConfidentialAccountApproved {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ConfidentialMintUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub auto_approve_new_accounts: bool,
+    pub auditor_elgamal_pubkey: Option<[u8; 32]>,
+}
+
+#[event]
+pub struct ConfidentialMinted {
+    pub mint: Pubkey,
+    pub minter: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64, // Opened against the proof commitments and charged to the minter's quota
+    pub daily_minted: u64,
+    pub total_minted: u64,
+    pub mint_allowance: u64,
+    pub confidential_supply: u64,
+}
+
+#[event]
+pub struct ConfidentialBurned {
+    pub mint: Pubkey,
+    pub burner: Pubkey,
+    pub from: Pubkey,
+    pub amount: u64, // Opened against the proof commitments
+    pub confidential_supply: u64,
+}
+
+#[event]
+pub struct ConfidentialSupplyReconciled {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous_confidential_supply: u64,
+    pub confidential_supply: u64, // Decrypted off-chain from the mint's confidential supply
+}
+
+#[event]
+pub struct DefaultAccountStateUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub was_frozen: bool,
+    pub is_frozen: bool,
+}
+
+#[event]
+pub struct Blacklisted {
+    pub mint: Pubkey,
+    pub blacklister: Pubkey,
+    pub account: Pubkey,
+    pub was_blacklisted: bool,
+    pub is_blacklisted: bool,
+    pub reason: BlacklistReason,
+    pub case_reference: [u8; 32],
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct BlacklistEntryClosed {
+    pub mint: Pubkey,
+    pub blacklister: Pubkey,
+    pub account: Pubkey,
+    pub was_blacklisted: bool,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct BlacklistEntryMigrated {
+    pub mint: Pubkey,
+    pub blacklister: Pubkey,
+    pub account: Pubkey,
+}
+
+#[event]
+pub struct Seized {
+    pub mint: Pubkey,
+    pub seizer: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AllowlistChanged {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub wallet: Pubkey,
+    pub was_allowed: bool,
+    pub is_allowed: bool,
+    pub expires_at: Option<i64>,
+    pub tier: u8,
+}
+
+#[event]
+pub struct AllowlistPolicyInitialized {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AllowlistTierUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub tier: u8,
+    pub previous_rules: AllowlistTier,
+    pub rules: AllowlistTier,
+}
+
+#[event]
+pub struct AllowlistEnabledUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub was_enabled: bool,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct AllowlistEntryMigrated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[event]
+pub struct AllowlistEntryClosed {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub wallet: Pubkey,
+    pub was_allowed: bool,
+    pub recipient: Pubkey,
+}
+
+impl From<&crate::state::RoleAccount> for RoleSnapshot {
+    fn from(roles: &crate::state::RoleAccount) -> Self {
+        Self {
+            burner: roles.burner,
+            pauser: roles.pauser,
+            blacklister: roles.blacklister,
+            seizer: roles.seizer,
+            minter_controller: roles.minter_controller,
+            allowlister: roles.allowlister,
+        }
+    }
+}
@@ -1,6 +1,7 @@
-use crate::{errors::*, state::*};
+use crate::{errors::*, events::*, state::*};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
     pub admin: Signer<'info>,
@@ -15,6 +16,51 @@ pub struct UpdateConfig<'info> {
     pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, RoleAccount>,
+    pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Must match the pending proposal for the requested role; checked in the handler.
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump
+    )]
+    pub roles: Account<'info, RoleAccount>,
+    pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateRoles<'info> {
     pub admin: Signer<'info>,
@@ -35,8 +81,9 @@ pub struct UpdateRoles<'info> {
     pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(is_active: bool, daily_mint_quota: u64)]
+#[instruction(is_active: bool, daily_mint_quota: u64, window_seconds: Option<u32>, quota_mode: MintQuotaMode)]
 pub struct ConfigureMinter<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -64,8 +111,9 @@ pub struct ConfigureMinter<'info> {
     pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(is_active: bool, daily_mint_quota: u64)]
+#[instruction(is_active: bool, daily_mint_quota: u64, window_seconds: Option<u32>, quota_mode: Option<MintQuotaMode>)]
 pub struct UpdateMinter<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -90,6 +138,68 @@ pub struct UpdateMinter<'info> {
     pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveMinter<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: Identity of the minter; validated by minter_config PDA seeds.
+    pub minter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [MinterConfig::SEED_PREFIX, mint.key().as_ref(), minter.key().as_ref()],
+        bump = minter_config.bump
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
+
+    /// CHECK: Receives the reclaimed rent; any account chosen by the master authority.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdjustMinterAllowance<'info> {
+    pub controller: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.minter_controller == controller.key() || config.master_authority == controller.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    /// CHECK: Identity of the minter; validated by minter_config PDA seeds.
+    pub minter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [MinterConfig::SEED_PREFIX, mint.key().as_ref(), minter.key().as_ref()],
+        bump = minter_config.bump
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
+
+    pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PauseUnpause<'info> {
     pub pauser: Signer<'info>,
@@ -110,18 +220,195 @@ pub struct PauseUnpause<'info> {
     pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 }
 
-pub fn handle_pause(ctx: Context<PauseUnpause>) -> Result<()> {
-    ctx.accounts.config.is_paused = true;
+/// Halt the operations in `flags` (see `StablecoinConfig::PAUSE_*`) on top of any already
+/// paused, recording `reason` and the current time.
+pub fn handle_pause(ctx: Context<PauseUnpause>, flags: u8, reason: PauseReason) -> Result<()> {
+    validate_pause_flags(flags)?;
+    let config = &mut ctx.accounts.config;
+    let previous_flags = config.pause_flags;
+    config.pause_flags |= flags;
+    config.pause_reason = reason;
+    config.paused_at = Clock::get()?.unix_timestamp;
+    emit_cpi!(Paused {
+        mint: ctx.accounts.mint.key(),
+        pauser: ctx.accounts.pauser.key(),
+        previous_flags,
+        pause_flags: config.pause_flags,
+        reason,
+        paused_at: config.paused_at,
+    });
+    Ok(())
+}
+
+/// Resume the operations in `flags`; others stay paused. The last pause reason and time are kept.
+pub fn handle_unpause(ctx: Context<PauseUnpause>, flags: u8) -> Result<()> {
+    validate_pause_flags(flags)?;
+    let config = &mut ctx.accounts.config;
+    let previous_flags = config.pause_flags;
+    config.pause_flags &= !flags;
+    emit_cpi!(Unpaused {
+        mint: ctx.accounts.mint.key(),
+        pauser: ctx.accounts.pauser.key(),
+        previous_flags,
+        pause_flags: config.pause_flags,
+    });
     Ok(())
 }
 
-pub fn handle_unpause(ctx: Context<PauseUnpause>) -> Result<()> {
-    ctx.accounts.config.is_paused = false;
+fn validate_pause_flags(flags: u8) -> Result<()> {
+    require!(
+        flags != 0 && flags & !StablecoinConfig::PAUSE_ALL == 0,
+        StablecoinError::InvalidPauseFlags
+    );
     Ok(())
 }
 
-pub fn handle_transfer_authority(ctx: Context<UpdateConfig>, new_authority: Pubkey) -> Result<()> {
-    ctx.accounts.config.master_authority = new_authority;
+/// Set or clear (`None`) the global supply cap enforced by `mint`. A cap below the current
+/// supply is allowed and simply blocks further issuance.
+pub fn handle_set_max_supply(ctx: Context<UpdateConfig>, max_supply: Option<u64>) -> Result<()> {
+    let previous_max_supply = ctx.accounts.config.max_supply;
+    ctx.accounts.config.max_supply = max_supply;
+    emit_cpi!(MaxSupplyUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        previous_max_supply,
+        max_supply,
+    });
+    Ok(())
+}
+
+/// Set or clear (`None`) the amount above which the transfer hook requires a travel-rule memo
+/// (a hash of the originator/beneficiary payload) in the transferring transaction.
+pub fn handle_set_travel_rule_threshold(
+    ctx: Context<UpdateConfig>,
+    threshold: Option<u64>,
+) -> Result<()> {
+    let previous_threshold = ctx.accounts.config.travel_rule_threshold;
+    ctx.accounts.config.travel_rule_threshold = threshold;
+    emit_cpi!(TravelRuleThresholdUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        previous_threshold,
+        threshold,
+    });
+    Ok(())
+}
+
+/// Set or clear (`None`) the Switchboard queue and feed that `mint_at_oracle_price` prices
+/// against. While unset, oracle-priced minting is disabled.
+pub fn handle_set_oracle_feed(ctx: Context<UpdateConfig>, feed: Option<OracleFeed>) -> Result<()> {
+    let previous_feed = ctx.accounts.config.oracle_feed;
+    ctx.accounts.config.oracle_feed = feed;
+    emit_cpi!(OracleFeedUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        previous_feed,
+        feed,
+    });
+    Ok(())
+}
+
+/// Set the outbound caps for `tier` (zero = no cap). The transfer hook applies them to wallets
+/// whose `TransferLimit` account selects the tier and does not override the cap itself.
+pub fn handle_set_transfer_limit_tier(
+    ctx: Context<UpdateConfig>,
+    tier: u8,
+    daily_limit: u64,
+    per_transfer_limit: u64,
+) -> Result<()> {
+    let slot = ctx
+        .accounts
+        .config
+        .transfer_limit_tiers
+        .get_mut(tier as usize)
+        .ok_or(StablecoinError::InvalidTransferLimitTier)?;
+    let previous_limits = *slot;
+    *slot = TransferLimitTier {
+        daily_limit,
+        per_transfer_limit,
+    };
+    emit_cpi!(TransferLimitTierUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        tier,
+        previous_limits,
+        limits: *slot,
+    });
+    Ok(())
+}
+
+/// Step 1 of a two-step handover: record `new_authority` as pending for `role`.
+/// Nothing changes until the proposed key signs `accept_authority`.
+pub fn handle_propose_authority(
+    ctx: Context<ProposeAuthority>,
+    role: AuthorityRole,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let current_authority = match ctx.accounts.roles.slot_mut(role) {
+        Some((current, pending)) => {
+            *pending = Some(new_authority);
+            *current
+        }
+        None => {
+            ctx.accounts.config.pending_authority = Some(new_authority);
+            ctx.accounts.config.master_authority
+        }
+    };
+    emit_cpi!(AuthorityTransferProposed {
+        mint: ctx.accounts.mint.key(),
+        role,
+        current_authority,
+        proposed_authority: new_authority,
+    });
+    Ok(())
+}
+
+/// Step 2: the proposed key signs to take over `role`; the pending slot is cleared.
+pub fn handle_accept_authority(ctx: Context<AcceptAuthority>, role: AuthorityRole) -> Result<()> {
+    let signer = ctx.accounts.new_authority.key();
+    let previous_authority = match ctx.accounts.roles.slot_mut(role) {
+        Some((current, pending)) => {
+            require!(
+                (*pending).ok_or(StablecoinError::NoPendingAuthority)? == signer,
+                StablecoinError::Unauthorized
+            );
+            *pending = None;
+            std::mem::replace(current, signer)
+        }
+        None => {
+            let config = &mut ctx.accounts.config;
+            require!(
+                config.pending_authority.ok_or(StablecoinError::NoPendingAuthority)? == signer,
+                StablecoinError::Unauthorized
+            );
+            config.pending_authority = None;
+            std::mem::replace(&mut config.master_authority, signer)
+        }
+    };
+    emit_cpi!(AuthorityTransferred {
+        mint: ctx.accounts.mint.key(),
+        role,
+        previous_authority,
+        new_authority: signer,
+    });
+    Ok(())
+}
+
+/// Master authority withdraws a pending proposal for `role`.
+pub fn handle_cancel_authority_transfer(
+    ctx: Context<ProposeAuthority>,
+    role: AuthorityRole,
+) -> Result<()> {
+    let cancelled_authority = match ctx.accounts.roles.slot_mut(role) {
+        Some((_, pending)) => pending.take(),
+        None => ctx.accounts.config.pending_authority.take(),
+    }
+    .ok_or(StablecoinError::NoPendingAuthority)?;
+    emit_cpi!(AuthorityTransferCancelled {
+        mint: ctx.accounts.mint.key(),
+        role,
+        cancelled_authority,
+    });
     Ok(())
 }
 
@@ -131,20 +418,43 @@ pub fn handle_update_roles(
     pauser: Option<Pubkey>,
     blacklister: Option<Pubkey>,
     seizer: Option<Pubkey>,
+    minter_controller: Option<Pubkey>,
+    allowlister: Option<Pubkey>,
 ) -> Result<()> {
     let roles = &mut ctx.accounts.roles;
+    let previous = RoleSnapshot::from(&**roles);
+    // A direct assignment supersedes any pending proposal for the same role.
     if let Some(b) = burner {
         roles.burner = b;
+        roles.pending_burner = None;
     }
     if let Some(p) = pauser {
         roles.pauser = p;
+        roles.pending_pauser = None;
     }
     if let Some(bl) = blacklister {
         roles.blacklister = bl;
+        roles.pending_blacklister = None;
     }
     if let Some(s) = seizer {
         roles.seizer = s;
+        roles.pending_seizer = None;
+    }
+    if let Some(mc) = minter_controller {
+        roles.minter_controller = mc;
+        roles.pending_minter_controller = None;
+    }
+    if let Some(al) = allowlister {
+        roles.allowlister = al;
+        roles.pending_allowlister = None;
     }
+    let current = RoleSnapshot::from(&**roles);
+    emit_cpi!(RolesUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        previous,
+        current,
+    });
     Ok(())
 }
 
@@ -152,12 +462,38 @@ pub fn handle_configure_minter(
     ctx: Context<ConfigureMinter>,
     is_active: bool,
     daily_mint_quota: u64,
+    window_seconds: Option<u32>,
+    quota_mode: MintQuotaMode,
 ) -> Result<()> {
+    let window_seconds = window_seconds.unwrap_or(MinterConfig::DEFAULT_WINDOW_SECONDS);
+    require!(
+        MinterConfig::is_valid_window(window_seconds),
+        StablecoinError::InvalidMintWindow
+    );
+    let now = Clock::get()?.unix_timestamp;
+
     let minter_config = &mut ctx.accounts.minter_config;
     minter_config.bump = ctx.bumps.minter_config;
     minter_config.minter = ctx.accounts.minter.key();
     minter_config.is_active = is_active;
     minter_config.daily_mint_quota = daily_mint_quota;
+    minter_config.set_window(window_seconds, now);
+    // Allowance starts empty; the minter controller approves issuance explicitly.
+    minter_config.quota_mode = quota_mode;
+    minter_config.mint_allowance = 0;
+    emit_cpi!(MinterUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        minter: ctx.accounts.minter.key(),
+        previous_is_active: false,
+        is_active,
+        previous_daily_mint_quota: 0,
+        daily_mint_quota,
+        previous_window_seconds: 0,
+        window_seconds,
+        previous_quota_mode: quota_mode,
+        quota_mode,
+    });
     Ok(())
 }
 
@@ -165,9 +501,94 @@ pub fn handle_update_minter(
     ctx: Context<UpdateMinter>,
     is_active: bool,
     daily_mint_quota: u64,
+    window_seconds: Option<u32>,
+    quota_mode: Option<MintQuotaMode>,
 ) -> Result<()> {
     let minter_config = &mut ctx.accounts.minter_config;
+    let previous_is_active = minter_config.is_active;
+    let previous_daily_mint_quota = minter_config.daily_mint_quota;
+    let previous_window_seconds = minter_config.window_seconds;
+    let previous_quota_mode = minter_config.quota_mode;
     minter_config.is_active = is_active;
     minter_config.daily_mint_quota = daily_mint_quota;
+    if let Some(window_seconds) = window_seconds {
+        require!(
+            MinterConfig::is_valid_window(window_seconds),
+            StablecoinError::InvalidMintWindow
+        );
+        minter_config.set_window(window_seconds, Clock::get()?.unix_timestamp);
+    }
+    if let Some(quota_mode) = quota_mode {
+        minter_config.quota_mode = quota_mode;
+    }
+    let window_seconds = minter_config.window_seconds;
+    let quota_mode = minter_config.quota_mode;
+    emit_cpi!(MinterUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        minter: ctx.accounts.minter.key(),
+        previous_is_active,
+        is_active,
+        previous_daily_mint_quota,
+        daily_mint_quota,
+        previous_window_seconds,
+        window_seconds,
+        previous_quota_mode,
+        quota_mode,
+    });
+    Ok(())
+}
+
+/// Close a minter's config PDA, returning its rent to `recipient`. The minter can be
+/// re-added later with `configure_minter`, starting from fresh counters.
+pub fn handle_remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
+    emit_cpi!(MinterRemoved {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        minter: ctx.accounts.minter.key(),
+        total_minted: ctx.accounts.minter_config.total_minted,
+        recipient: ctx.accounts.recipient.key(),
+    });
+    Ok(())
+}
+
+/// Minter controller approves `amount` more issuance for an Allowance-mode minter.
+pub fn handle_increase_minter_allowance(
+    ctx: Context<AdjustMinterAllowance>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, StablecoinError::InvalidAmount);
+    let minter_config = &mut ctx.accounts.minter_config;
+    let previous_allowance = minter_config.mint_allowance;
+    minter_config.mint_allowance = previous_allowance
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    emit_cpi!(MinterAllowanceUpdated {
+        mint: ctx.accounts.mint.key(),
+        controller: ctx.accounts.controller.key(),
+        minter: ctx.accounts.minter.key(),
+        previous_allowance,
+        allowance: ctx.accounts.minter_config.mint_allowance,
+    });
+    Ok(())
+}
+
+/// Minter controller withdraws up to `amount` of unused allowance; clamps at zero so
+/// `u64::MAX` revokes everything.
+pub fn handle_decrease_minter_allowance(
+    ctx: Context<AdjustMinterAllowance>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, StablecoinError::InvalidAmount);
+    let minter_config = &mut ctx.accounts.minter_config;
+    let previous_allowance = minter_config.mint_allowance;
+    minter_config.mint_allowance = previous_allowance.saturating_sub(amount);
+    emit_cpi!(MinterAllowanceUpdated {
+        mint: ctx.accounts.mint.key(),
+        controller: ctx.accounts.controller.key(),
+        minter: ctx.accounts.minter.key(),
+        previous_allowance,
+        allowance: ctx.accounts.minter_config.mint_allowance,
+    });
     Ok(())
 }
@@ -1,7 +1,8 @@
-use crate::{errors::*, state::*};
+use crate::{errors::*, events::*, instructions::admin::UpdateConfig, state::*};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddToAllowlist<'info> {
     #[account(mut)]
@@ -17,6 +18,13 @@ pub struct AddToAllowlist<'info> {
     #[account(address = config.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.allowlister == authority.key() || config.master_authority == authority.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
     /// CHECK: The wallet being allowlisted (we only need its pubkey for the PDA).
     pub wallet: UncheckedAccount<'info>,
 
@@ -32,6 +40,7 @@ pub struct AddToAllowlist<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RemoveFromAllowlist<'info> {
     #[account(mut)]
@@ -47,6 +56,13 @@ pub struct RemoveFromAllowlist<'info> {
     #[account(address = config.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.allowlister == authority.key() || config.master_authority == authority.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
     /// CHECK: The wallet being removed from allowlist.
     pub wallet: UncheckedAccount<'info>,
 
@@ -58,6 +74,7 @@ pub struct RemoveFromAllowlist<'info> {
     pub allowlist_entry: Account<'info, AllowlistEntry>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateAllowlistEntry<'info> {
     #[account(mut)]
@@ -73,6 +90,13 @@ pub struct UpdateAllowlistEntry<'info> {
     #[account(address = config.mint)]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.allowlister == authority.key() || config.master_authority == authority.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
     /// CHECK: The wallet whose allowlist status is being updated.
     pub wallet: UncheckedAccount<'info>,
 
@@ -84,39 +108,250 @@ pub struct UpdateAllowlistEntry<'info> {
     pub allowlist_entry: Account<'info, AllowlistEntry>,
 }
 
-pub fn handle_add_to_allowlist(ctx: Context<AddToAllowlist>) -> Result<()> {
-    require!(
-        ctx.accounts.config.master_authority == ctx.accounts.authority.key(),
-        StablecoinError::Unauthorized
-    );
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAllowlistEntry<'info> {
+    pub authority: Signer<'info>,
+
+    // No enable_allowlist constraint: rent can still be reclaimed after the allowlist is turned off.
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.allowlister == authority.key() || config.master_authority == authority.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    /// CHECK: The wallet whose allowlist entry is being closed.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [AllowlistEntry::SEED_PREFIX, config.mint.as_ref(), wallet.key().as_ref()],
+        bump = allowlist_entry.bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    /// CHECK: Receives the reclaimed rent.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeAllowlistPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AllowlistPolicy::INIT_SPACE,
+        seeds = [AllowlistPolicy::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub allowlist_policy: Account<'info, AllowlistPolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAllowlistTier<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [AllowlistPolicy::SEED_PREFIX, mint.key().as_ref()],
+        bump = allowlist_policy.bump
+    )]
+    pub allowlist_policy: Account<'info, AllowlistPolicy>,
+}
+
+pub fn handle_add_to_allowlist(
+    ctx: Context<AddToAllowlist>,
+    expires_at: Option<i64>,
+    tier: u8,
+) -> Result<()> {
+    validate_expiry(expires_at)?;
+    validate_tier(tier)?;
 
     let entry = &mut ctx.accounts.allowlist_entry;
     entry.bump = ctx.bumps.allowlist_entry;
     entry.wallet = ctx.accounts.wallet.key();
     entry.is_allowed = true;
+    entry.expires_at = expires_at;
+    entry.tier = tier;
+    emit_cpi!(AllowlistChanged {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        wallet: ctx.accounts.wallet.key(),
+        was_allowed: false,
+        is_allowed: true,
+        expires_at,
+        tier,
+    });
     Ok(())
 }
 
 pub fn handle_remove_from_allowlist(ctx: Context<RemoveFromAllowlist>) -> Result<()> {
-    require!(
-        ctx.accounts.config.master_authority == ctx.accounts.authority.key(),
-        StablecoinError::Unauthorized
-    );
-
     let entry = &mut ctx.accounts.allowlist_entry;
+    let was_allowed = entry.is_allowed;
     entry.wallet = ctx.accounts.wallet.key();
     entry.is_allowed = false;
+    emit_cpi!(AllowlistChanged {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        wallet: ctx.accounts.wallet.key(),
+        was_allowed,
+        is_allowed: false,
+        expires_at: ctx.accounts.allowlist_entry.expires_at,
+        tier: ctx.accounts.allowlist_entry.tier,
+    });
     Ok(())
 }
 
-pub fn handle_update_allowlist_entry(ctx: Context<UpdateAllowlistEntry>, is_allowed: bool) -> Result<()> {
-    require!(
-        ctx.accounts.config.master_authority == ctx.accounts.authority.key(),
-        StablecoinError::Unauthorized
-    );
+pub fn handle_update_allowlist_entry(
+    ctx: Context<UpdateAllowlistEntry>,
+    is_allowed: bool,
+    expires_at: Option<i64>,
+    tier: u8,
+) -> Result<()> {
+    validate_expiry(expires_at)?;
+    validate_tier(tier)?;
 
     let entry = &mut ctx.accounts.allowlist_entry;
+    let was_allowed = entry.is_allowed;
     entry.wallet = ctx.accounts.wallet.key();
     entry.is_allowed = is_allowed;
+    entry.expires_at = expires_at;
+    entry.tier = tier;
+    emit_cpi!(AllowlistChanged {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        wallet: ctx.accounts.wallet.key(),
+        was_allowed,
+        is_allowed,
+        expires_at,
+        tier,
+    });
+    Ok(())
+}
+
+/// Close an allowlist entry and return its rent to `recipient`. A missing entry is treated
+/// as not allowed by the transfer hook.
+pub fn handle_close_allowlist_entry(ctx: Context<CloseAllowlistEntry>) -> Result<()> {
+    emit_cpi!(AllowlistEntryClosed {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        wallet: ctx.accounts.wallet.key(),
+        was_allowed: ctx.accounts.allowlist_entry.is_allowed,
+        recipient: ctx.accounts.recipient.key(),
+    });
+    Ok(())
+}
+
+/// Turn SSS-3 allowlist enforcement on or off (master authority). The transfer hook only passes
+/// allowlist PDAs once its extra-account-meta list is rebuilt with
+/// `transfer_hook::update_extra_account_meta_list`, which reads this flag.
+pub fn handle_set_allowlist_enabled(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+    require!(
+        ctx.accounts.config.enable_transfer_hook,
+        StablecoinError::ComplianceNotEnabled
+    );
+    let was_enabled = ctx.accounts.config.enable_allowlist;
+    ctx.accounts.config.enable_allowlist = enabled;
+    emit_cpi!(AllowlistEnabledUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        was_enabled,
+        enabled,
+    });
+    Ok(())
+}
+
+/// Create the mint's allowlist policy (master authority) with every tier unrestricted. Once it
+/// exists the transfer hook enforces each tier's amount cap and allowed recipient tiers.
+pub fn handle_initialize_allowlist_policy(ctx: Context<InitializeAllowlistPolicy>) -> Result<()> {
+    let policy = &mut ctx.accounts.allowlist_policy;
+    policy.bump = ctx.bumps.allowlist_policy;
+    policy.mint = ctx.accounts.mint.key();
+    policy.tiers = [AllowlistTier::UNRESTRICTED; ALLOWLIST_TIERS];
+    emit_cpi!(AllowlistPolicyInitialized {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+    });
+    Ok(())
+}
+
+/// Set what wallets in `tier` may send: a per-transfer cap (0 = none) and a bitmask of the
+/// tiers they may send to (bit n = tier n).
+pub fn handle_set_allowlist_tier(
+    ctx: Context<SetAllowlistTier>,
+    tier: u8,
+    max_transfer_amount: u64,
+    allowed_recipient_tiers: u8,
+) -> Result<()> {
+    validate_tier(tier)?;
+    let slot = &mut ctx.accounts.allowlist_policy.tiers[tier as usize];
+    let previous_rules = *slot;
+    *slot = AllowlistTier {
+        max_transfer_amount,
+        allowed_recipient_tiers,
+    };
+    emit_cpi!(AllowlistTierUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        tier,
+        previous_rules,
+        rules: *slot,
+    });
+    Ok(())
+}
+
+fn validate_tier(tier: u8) -> Result<()> {
+    require!(
+        (tier as usize) < ALLOWLIST_TIERS,
+        StablecoinError::InvalidAllowlistTier
+    );
+    Ok(())
+}
+
+fn validate_expiry(expires_at: Option<i64>) -> Result<()> {
+    if let Some(expires_at) = expires_at {
+        require!(
+            expires_at > Clock::get()?.unix_timestamp,
+            StablecoinError::InvalidExpiry
+        );
+    }
     Ok(())
 }
@@ -1,7 +1,8 @@
-use crate::{errors::*, state::*};
+use crate::{errors::*, events::*, state::*};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{burn, Burn, Mint, Token2022, TokenAccount};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct BurnTokens<'info> {
     #[account(mut)]
@@ -10,7 +11,7 @@ pub struct BurnTokens<'info> {
     #[account(
         seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
         bump = config.bump,
-        constraint = !config.is_paused @ StablecoinError::ProgramPaused
+        constraint = !config.is_paused(StablecoinConfig::PAUSE_BURN) @ StablecoinError::ProgramPaused
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -49,5 +50,16 @@ pub fn handle_burn(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     burn(cpi_ctx, amount)?;
 
+    // Mint is not reloaded after the CPI, so `supply` still holds the pre-CPI value.
+    let supply_before = ctx.accounts.mint.supply;
+    emit_cpi!(Burned {
+        mint: ctx.accounts.mint.key(),
+        burner: ctx.accounts.burner.key(),
+        from: ctx.accounts.from_account.key(),
+        amount,
+        supply_before,
+        supply_after: supply_before.saturating_sub(amount),
+    });
+
     Ok(())
 }
@@ -1,9 +1,17 @@
-use crate::{errors::*, state::*};
+use crate::{errors::*, events::*, state::*};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
     freeze_account, thaw_account, FreezeAccount, Mint, ThawAccount, Token2022, TokenAccount,
 };
+use spl_token_2022::{
+    extension::{
+        default_account_state::{instruction::update_default_account_state, DefaultAccountState},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{AccountState, Mint as SplMint},
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddToBlacklist<'info> {
     #[account(mut)]
@@ -38,6 +46,7 @@ pub struct AddToBlacklist<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RemoveFromBlacklist<'info> {
     #[account(mut)]
@@ -69,6 +78,7 @@ pub struct RemoveFromBlacklist<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateBlacklistEntry<'info> {
     #[account(mut)]
@@ -100,6 +110,43 @@ pub struct UpdateBlacklistEntry<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBlacklistEntry<'info> {
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.blacklister == blacklister.key() || config.master_authority == blacklister.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    /// CHECK: The account whose blacklist entry is being closed.
+    pub target_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [BlacklistEntry::SEED_PREFIX, mint.key().as_ref(), target_account.key().as_ref()],
+        bump = blacklist_entry.bump
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    /// CHECK: Receives the reclaimed rent.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct FreezeThaw<'info> {
     pub blacklister: Signer<'info>,
@@ -132,6 +179,35 @@ pub struct FreezeThaw<'info> {
     pub token_program: Program<'info, Token2022>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDefaultAccountState<'info> {
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.blacklister == blacklister.key() || config.master_authority == blacklister.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Seize<'info> {
     pub seizer: Signer<'info>,
@@ -139,7 +215,8 @@ pub struct Seize<'info> {
     #[account(
         seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
         bump = config.bump,
-        constraint = config.enable_permanent_delegate @ StablecoinError::ComplianceNotEnabled
+        constraint = config.enable_permanent_delegate @ StablecoinError::ComplianceNotEnabled,
+        constraint = !config.is_paused(StablecoinConfig::PAUSE_SEIZE) @ StablecoinError::ProgramPaused
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -180,24 +257,58 @@ pub struct Seize<'info> {
     pub source_blacklist: UncheckedAccount<'info>,
     /// CHECK: Destination blacklist entry (Index 7 in hook)
     pub dest_blacklist: UncheckedAccount<'info>,
-    /// CHECK: Config PDA (Index 8, SSS-3 only). Required when config.enable_allowlist.
+    /// CHECK: Config PDA (Index 8 in hook); pass the config account.
     pub config_allowlist: UncheckedAccount<'info>,
     /// CHECK: Source allowlist entry (Index 9, SSS-3 only)
     pub source_allowlist: UncheckedAccount<'info>,
     /// CHECK: Dest allowlist entry (Index 10, SSS-3 only)
     pub dest_allowlist: UncheckedAccount<'info>,
+    /// CHECK: Blacklist entry of the transfer authority (the config PDA)
+    pub authority_blacklist: UncheckedAccount<'info>,
+    /// CHECK: Transfer hook's TransferLimit PDA for the source owner; writable in the hook's list.
+    #[account(mut)]
+    pub source_transfer_limit: UncheckedAccount<'info>,
+    /// CHECK: Allowlist policy PDA (SSS-3 only)
+    pub allowlist_policy: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar (travel-rule memo check in the hook)
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
 }
 
-pub fn handle_add_to_blacklist(ctx: Context<AddToBlacklist>) -> Result<()> {
+pub fn handle_add_to_blacklist(
+    ctx: Context<AddToBlacklist>,
+    reason: BlacklistReason,
+    case_reference: [u8; 32],
+    expires_at: Option<i64>,
+) -> Result<()> {
     require!(
         ctx.accounts.config.enable_transfer_hook,
         StablecoinError::ComplianceNotEnabled
     );
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > now, StablecoinError::InvalidExpiry);
+    }
 
     let entry = &mut ctx.accounts.blacklist_entry;
     entry.bump = ctx.bumps.blacklist_entry;
     entry.account = ctx.accounts.target_account.key();
     entry.is_blacklisted = true;
+    entry.reason = reason;
+    entry.case_reference = case_reference;
+    entry.blacklisted_at = now;
+    entry.blacklisted_by = ctx.accounts.blacklister.key();
+    entry.expires_at = expires_at;
+    emit_cpi!(Blacklisted {
+        mint: ctx.accounts.mint.key(),
+        blacklister: ctx.accounts.blacklister.key(),
+        account: ctx.accounts.target_account.key(),
+        was_blacklisted: false,
+        is_blacklisted: true,
+        reason,
+        case_reference,
+        expires_at,
+    });
     Ok(())
 }
 
@@ -208,26 +319,80 @@ pub fn handle_remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result
     );
 
     let entry = &mut ctx.accounts.blacklist_entry;
+    let was_blacklisted = entry.is_blacklisted;
     entry.account = ctx.accounts.target_account.key();
     entry.is_blacklisted = false;
+    emit_cpi!(Blacklisted {
+        mint: ctx.accounts.mint.key(),
+        blacklister: ctx.accounts.blacklister.key(),
+        account: ctx.accounts.target_account.key(),
+        was_blacklisted,
+        is_blacklisted: false,
+        reason: ctx.accounts.blacklist_entry.reason,
+        case_reference: ctx.accounts.blacklist_entry.case_reference,
+        expires_at: ctx.accounts.blacklist_entry.expires_at,
+    });
     Ok(())
 }
 
-pub fn handle_update_blacklist_entry(ctx: Context<UpdateBlacklistEntry>, is_blacklisted: bool) -> Result<()> {
+/// Set the listing state together with its reason and case reference. Relisting restamps
+/// `blacklisted_at` / `blacklisted_by`; delisting keeps them as the record of the last listing.
+pub fn handle_update_blacklist_entry(
+    ctx: Context<UpdateBlacklistEntry>,
+    is_blacklisted: bool,
+    reason: BlacklistReason,
+    case_reference: [u8; 32],
+    expires_at: Option<i64>,
+) -> Result<()> {
     require!(
         ctx.accounts.config.enable_transfer_hook,
         StablecoinError::ComplianceNotEnabled
     );
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > now, StablecoinError::InvalidExpiry);
+    }
 
     let entry = &mut ctx.accounts.blacklist_entry;
+    let was_blacklisted = entry.is_blacklisted;
     entry.account = ctx.accounts.target_account.key();
     entry.is_blacklisted = is_blacklisted;
+    entry.reason = reason;
+    entry.case_reference = case_reference;
+    entry.expires_at = expires_at;
+    if is_blacklisted {
+        entry.blacklisted_at = now;
+        entry.blacklisted_by = ctx.accounts.blacklister.key();
+    }
+    emit_cpi!(Blacklisted {
+        mint: ctx.accounts.mint.key(),
+        blacklister: ctx.accounts.blacklister.key(),
+        account: ctx.accounts.target_account.key(),
+        was_blacklisted,
+        is_blacklisted,
+        reason,
+        case_reference,
+        expires_at,
+    });
+    Ok(())
+}
+
+/// Close a blacklist entry and return its rent to `recipient`. The transfer hook treats a
+/// missing entry as not blacklisted, so closing an active entry also lifts the block.
+pub fn handle_close_blacklist_entry(ctx: Context<CloseBlacklistEntry>) -> Result<()> {
+    emit_cpi!(BlacklistEntryClosed {
+        mint: ctx.accounts.mint.key(),
+        blacklister: ctx.accounts.blacklister.key(),
+        account: ctx.accounts.target_account.key(),
+        was_blacklisted: ctx.accounts.blacklist_entry.is_blacklisted,
+        recipient: ctx.accounts.recipient.key(),
+    });
     Ok(())
 }
 
 pub fn handle_freeze_account(ctx: Context<FreezeThaw>) -> Result<()> {
     let bump = ctx.accounts.config.bump;
-    let mint_key = ctx.accounts.mint.key().clone();
+    let mint_key = ctx.accounts.mint.key();
     let mint_key = mint_key.as_ref();
     let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key, &[bump]];
     let signer = &[&seeds[..]];
@@ -242,12 +407,18 @@ pub fn handle_freeze_account(ctx: Context<FreezeThaw>) -> Result<()> {
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     freeze_account(cpi_ctx)?;
 
+    emit_cpi!(AccountFrozen {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.blacklister.key(),
+        token_account: ctx.accounts.token_account.key(),
+    });
+
     Ok(())
 }
 
 pub fn handle_thaw_account(ctx: Context<FreezeThaw>) -> Result<()> {
     let bump = ctx.accounts.config.bump;
-    let mint_key = ctx.accounts.mint.key().clone();
+    let mint_key = ctx.accounts.mint.key();
     let mint_key = mint_key.as_ref();
     let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key, &[bump]];
     let signer = &[&seeds[..]];
@@ -262,18 +433,83 @@ pub fn handle_thaw_account(ctx: Context<FreezeThaw>) -> Result<()> {
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     thaw_account(cpi_ctx)?;
 
+    emit_cpi!(AccountThawed {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.blacklister.key(),
+        token_account: ctx.accounts.token_account.key(),
+    });
+
+    Ok(())
+}
+
+/// Switch the mint's DefaultAccountState between Frozen and Initialized. With `frozen = true`
+/// every newly opened token account starts frozen until the blacklister thaws it (KYC gating).
+pub fn handle_set_default_account_state(
+    ctx: Context<SetDefaultAccountState>,
+    frozen: bool,
+) -> Result<()> {
+    {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+        require!(
+            mint_state.get_extension::<DefaultAccountState>().is_ok(),
+            StablecoinError::DefaultAccountStateNotEnabled
+        );
+    }
+
+    let bump = ctx.accounts.config.bump;
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    let state = if frozen {
+        AccountState::Frozen
+    } else {
+        AccountState::Initialized
+    };
+    let ix = update_default_account_state(
+        &ctx.accounts.token_program.key(),
+        &mint_key,
+        &ctx.accounts.config.key(),
+        &[],
+        &state,
+    )?;
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.config.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    let was_frozen = ctx.accounts.config.default_account_frozen;
+    ctx.accounts.config.default_account_frozen = frozen;
+
+    emit_cpi!(DefaultAccountStateUpdated {
+        mint: mint_key,
+        authority: ctx.accounts.blacklister.key(),
+        was_frozen,
+        is_frozen: frozen,
+    });
+
     Ok(())
 }
 
 /// Seize: permanent delegate transfers from frozen account to treasury via Token-2022
 /// transfer_checked. Account order must match the mint's extra-account-metas (validation PDA):
 /// from, mint, to, authority, extra_meta_list, stablecoin_program, source_blacklist, dest_blacklist,
-/// [config_allowlist, source_allowlist, dest_allowlist if enable_allowlist], transfer_hook_program.
+/// config, [source_allowlist, dest_allowlist if enable_allowlist], authority_blacklist,
+/// source_transfer_limit, [allowlist_policy if enable_allowlist], instructions_sysvar,
+/// transfer_hook_program.
+/// Token-2022 picks accounts by key, so the newer ones are harmless for lists built before
+/// they were added.
 pub fn handle_seize(ctx: Context<Seize>, amount: u64) -> Result<()> {
     require!(amount > 0, StablecoinError::InvalidAmount);
 
     let bump = ctx.accounts.config.bump;
-    let mint_key = ctx.accounts.mint.key().clone();
+    let mint_key = ctx.accounts.mint.key();
     let mint_key = mint_key.as_ref();
     let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key, &[bump]];
     let signer = &[&seeds[..]];
@@ -287,12 +523,18 @@ pub fn handle_seize(ctx: Context<Seize>, amount: u64) -> Result<()> {
         AccountMeta::new_readonly(ctx.accounts.stablecoin_program.key(), false),
         AccountMeta::new_readonly(ctx.accounts.source_blacklist.key(), false),
         AccountMeta::new_readonly(ctx.accounts.dest_blacklist.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.config_allowlist.key(), false),
     ];
     if ctx.accounts.config.enable_allowlist {
-        account_metas.push(AccountMeta::new_readonly(ctx.accounts.config_allowlist.key(), false));
         account_metas.push(AccountMeta::new_readonly(ctx.accounts.source_allowlist.key(), false));
         account_metas.push(AccountMeta::new_readonly(ctx.accounts.dest_allowlist.key(), false));
     }
+    account_metas.push(AccountMeta::new_readonly(ctx.accounts.authority_blacklist.key(), false));
+    account_metas.push(AccountMeta::new(ctx.accounts.source_transfer_limit.key(), false));
+    if ctx.accounts.config.enable_allowlist {
+        account_metas.push(AccountMeta::new_readonly(ctx.accounts.allowlist_policy.key(), false));
+    }
+    account_metas.push(AccountMeta::new_readonly(ctx.accounts.instructions_sysvar.key(), false));
     account_metas.push(AccountMeta::new_readonly(ctx.accounts.transfer_hook_program.key(), false));
 
     let ix = spl_token_2022::instruction::transfer_checked(
@@ -323,15 +565,29 @@ pub fn handle_seize(ctx: Context<Seize>, amount: u64) -> Result<()> {
         ctx.accounts.stablecoin_program.to_account_info(),
         ctx.accounts.source_blacklist.to_account_info(),
         ctx.accounts.dest_blacklist.to_account_info(),
+        ctx.accounts.config_allowlist.to_account_info(),
     ];
     if ctx.accounts.config.enable_allowlist {
-        hook_accounts.push(ctx.accounts.config_allowlist.to_account_info());
         hook_accounts.push(ctx.accounts.source_allowlist.to_account_info());
         hook_accounts.push(ctx.accounts.dest_allowlist.to_account_info());
     }
+    hook_accounts.push(ctx.accounts.authority_blacklist.to_account_info());
+    hook_accounts.push(ctx.accounts.source_transfer_limit.to_account_info());
+    if ctx.accounts.config.enable_allowlist {
+        hook_accounts.push(ctx.accounts.allowlist_policy.to_account_info());
+    }
+    hook_accounts.push(ctx.accounts.instructions_sysvar.to_account_info());
     hook_accounts.push(ctx.accounts.transfer_hook_program.to_account_info());
 
     anchor_lang::solana_program::program::invoke_signed(&manual_ix, &hook_accounts, signer)?;
 
+    emit_cpi!(Seized {
+        mint: ctx.accounts.mint.key(),
+        seizer: ctx.accounts.seizer.key(),
+        from: ctx.accounts.from_account.key(),
+        to: ctx.accounts.to_account.key(),
+        amount,
+    });
+
     Ok(())
 }
@@ -0,0 +1,570 @@
+use crate::{
+    errors::*,
+    events::*,
+    instructions::mint::{charge_minter_quota, check_supply_cap},
+    state::*,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{
+        confidential_mint_burn::{
+            instruction::{
+                BurnInstructionData, ConfidentialMintBurnInstruction, MintInstructionData,
+            },
+            MAXIMUM_DEPOSIT_TRANSFER_AMOUNT,
+        },
+        confidential_transfer::instruction::{approve_account, update_mint},
+    },
+    instruction::TokenInstruction,
+    solana_zk_sdk::{
+        encryption::pod::{auth_encryption::PodAeCiphertext, elgamal::PodElGamalPubkey},
+        zk_elgamal_proof_program::{
+            self,
+            proof_data::{BatchedGroupedCiphertext3HandlesValidityProofContext, ProofType},
+            state::ProofContextState,
+        },
+    },
+};
+use anchor_spl::token_interface::{
+    spl_pod::bytemuck::{pod_bytes_of, pod_from_bytes},
+    Mint, Token2022, TokenAccount,
+};
+use solana_curve25519::{
+    ristretto::{multiscalar_multiply_ristretto, PodRistrettoPoint},
+    scalar::PodScalar,
+};
+
+/// Pedersen generators as compressed Ristretto points: `G` is the basepoint and `H` the
+/// zk-sdk's blinding generator (hash of `G`). Amount commitments are `amount * G + r * H`.
+const PEDERSEN_G: PodRistrettoPoint = PodRistrettoPoint([
+    226, 242, 174, 10, 106, 188, 78, 113, 168, 132, 169, 97, 197, 0, 81, 95, 88, 227, 11, 106, 165,
+    130, 221, 141, 182, 166, 89, 69, 224, 141, 45, 118,
+]);
+const PEDERSEN_H: PodRistrettoPoint = PodRistrettoPoint([
+    140, 146, 64, 180, 86, 169, 230, 220, 101, 195, 119, 161, 4, 141, 116, 95, 148, 160, 140, 219,
+    127, 68, 203, 205, 123, 70, 243, 64, 72, 135, 17, 52,
+]);
+
+/// Token-2022 splits mint and burn amounts into a 16-bit low and 32-bit high part.
+const AMOUNT_LO_BITS: u32 = 16;
+
+/// Supply encryption settings for the ConfidentialMintBurn extension, passed to `initialize`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfidentialMintBurnParams {
+    pub supply_elgamal_pubkey: [u8; 32], // Encrypts the confidential supply on the mint
+    pub decryptable_supply: [u8; 36],    // Zero supply encrypted under the supply AES key
+}
+
+/// Pedersen openings of the low/high amount commitments in a confidential mint or burn's
+/// ciphertext validity proof, as returned by the zk-sdk's mint/burn proof generation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ConfidentialAmountOpening {
+    pub lo: [u8; 32], // Opening of the commitment to the low 16 bits
+    pub hi: [u8; 32], // Opening of the commitment to the high 32 bits
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveConfidentialAccount<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.enable_confidential_transfers @ StablecoinError::ConfidentialTransfersNotEnabled
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.allowlister == authority.key() || config.master_authority == authority.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key()
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfidentialMint<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized,
+        constraint = config.enable_confidential_transfers @ StablecoinError::ConfidentialTransfersNotEnabled
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReconcileConfidentialSupply<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized,
+        constraint = config.enable_confidential_mint_burn @ StablecoinError::ConfidentialMintBurnNotEnabled
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(address = config.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfidentialMintTokens<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = !config.is_paused(StablecoinConfig::PAUSE_MINT) @ StablecoinError::ProgramPaused,
+        constraint = config.enable_confidential_mint_burn @ StablecoinError::ConfidentialMintBurnNotEnabled
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        seeds = [MinterConfig::SEED_PREFIX, mint.key().as_ref(), minter.key().as_ref()],
+        bump = minter_config.bump,
+        constraint = minter_config.is_active @ StablecoinError::MinterInactive
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = to_account.mint == mint.key()
+    )]
+    pub to_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Pre-verified CiphertextCommitmentEquality context state; Token-2022 checks owner and contents.
+    pub equality_proof: UncheckedAccount<'info>,
+    /// CHECK: Pre-verified BatchedGroupedCiphertext3HandlesValidity context state; owner and type
+    /// checked in `verify_amount_opening` and again by Token-2022.
+    pub ciphertext_validity_proof: UncheckedAccount<'info>,
+    /// CHECK: Pre-verified BatchedRangeProofU128 context state; checked by Token-2022.
+    pub range_proof: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfidentialBurnTokens<'info> {
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = !config.is_paused(StablecoinConfig::PAUSE_BURN) @ StablecoinError::ProgramPaused,
+        constraint = config.enable_confidential_mint_burn @ StablecoinError::ConfidentialMintBurnNotEnabled
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.burner == burner.key() || config.master_authority == burner.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Token-2022 only accepts the account owner as authority for confidential burns.
+    #[account(
+        mut,
+        constraint = from_account.mint == mint.key(),
+        constraint = from_account.owner == burner.key() @ StablecoinError::Unauthorized
+    )]
+    pub from_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Pre-verified CiphertextCommitmentEquality context state; Token-2022 checks owner and contents.
+    pub equality_proof: UncheckedAccount<'info>,
+    /// CHECK: Pre-verified BatchedGroupedCiphertext3HandlesValidity context state; owner and type
+    /// checked in `verify_amount_opening` and again by Token-2022.
+    pub ciphertext_validity_proof: UncheckedAccount<'info>,
+    /// CHECK: Pre-verified BatchedRangeProofU128 context state; checked by Token-2022.
+    pub range_proof: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Approve a token account's confidential-transfer configuration (allowlister or master
+/// authority). Needed before the account can use confidential balances when the mint does
+/// not auto-approve new accounts; the config PDA signs as the extension's authority.
+pub fn handle_approve_confidential_account(ctx: Context<ApproveConfidentialAccount>) -> Result<()> {
+    let bump = ctx.accounts.config.bump;
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    let ix = approve_account(
+        &ctx.accounts.token_program.key(),
+        &ctx.accounts.token_account.key(),
+        &mint_key,
+        &ctx.accounts.config.key(),
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.config.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    emit_cpi!(ConfidentialAccountApproved {
+        mint: mint_key,
+        authority: ctx.accounts.authority.key(),
+        token_account: ctx.accounts.token_account.key(),
+        owner: ctx.accounts.token_account.owner,
+    });
+    Ok(())
+}
+
+/// Rotate (or remove, with `None`) the auditor ElGamal key and set whether new confidential
+/// accounts are approved automatically. Master authority only.
+pub fn handle_update_confidential_mint(
+    ctx: Context<UpdateConfidentialMint>,
+    auto_approve_new_accounts: bool,
+    auditor_elgamal_pubkey: Option<[u8; 32]>,
+) -> Result<()> {
+    let bump = ctx.accounts.config.bump;
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    let ix = update_mint(
+        &ctx.accounts.token_program.key(),
+        &mint_key,
+        &ctx.accounts.config.key(),
+        &[],
+        auto_approve_new_accounts,
+        auditor_pod_pubkey(auditor_elgamal_pubkey)?,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.config.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    emit_cpi!(ConfidentialMintUpdated {
+        mint: mint_key,
+        authority: ctx.accounts.admin.key(),
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey,
+    });
+    Ok(())
+}
+
+/// Overwrite `config.confidential_supply` with the mint's actual confidential supply (master
+/// authority only). Holders can burn with Token-2022's confidential `Burn` directly, which this
+/// program never sees, so the counter only ever overstates supply; the master decrypts the mint's
+/// confidential supply with the supply ElGamal key off-chain and writes it back here.
+pub fn handle_reconcile_confidential_supply(
+    ctx: Context<ReconcileConfidentialSupply>,
+    confidential_supply: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_confidential_supply = config.confidential_supply;
+    config.confidential_supply = confidential_supply;
+
+    emit_cpi!(ConfidentialSupplyReconciled {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        previous_confidential_supply,
+        confidential_supply,
+    });
+    Ok(())
+}
+
+/// Mint into `to_account`'s pending confidential balance (active minter only). The minter
+/// declares `amount` with the openings of the proof's amount commitments; the program checks
+/// they match before charging the same quota and supply cap as `mint`.
+pub fn handle_confidential_mint(
+    ctx: Context<ConfidentialMintTokens>,
+    amount: u64,
+    amount_opening: ConfidentialAmountOpening,
+    new_decryptable_supply: [u8; 36],
+) -> Result<()> {
+    require!(
+        amount > 0 && amount <= MAXIMUM_DEPOSIT_TRANSFER_AMOUNT,
+        StablecoinError::InvalidAmount
+    );
+    verify_amount_opening(
+        &ctx.accounts.ciphertext_validity_proof,
+        amount,
+        &amount_opening,
+    )?;
+
+    check_supply_cap(&ctx.accounts.config, &ctx.accounts.mint, amount)?;
+    charge_minter_quota(&mut ctx.accounts.minter_config, amount)?;
+
+    let bump = ctx.accounts.config.bump;
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    let ix = confidential_mint_burn_instruction(
+        &ctx.accounts.token_program.key(),
+        ctx.accounts.to_account.key(),
+        mint_key,
+        [
+            ctx.accounts.equality_proof.key(),
+            ctx.accounts.ciphertext_validity_proof.key(),
+            ctx.accounts.range_proof.key(),
+        ],
+        ctx.accounts.config.key(),
+        ConfidentialMintBurnInstruction::Mint,
+        pod_bytes_of(&MintInstructionData {
+            new_decryptable_supply: PodAeCiphertext::from(new_decryptable_supply),
+            equality_proof_instruction_offset: 0,
+            ciphertext_validity_proof_instruction_offset: 0,
+            range_proof_instruction_offset: 0,
+        }),
+    );
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.to_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.equality_proof.to_account_info(),
+            ctx.accounts.ciphertext_validity_proof.to_account_info(),
+            ctx.accounts.range_proof.to_account_info(),
+            ctx.accounts.config.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.confidential_supply = config
+        .confidential_supply
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit_cpi!(ConfidentialMinted {
+        mint: mint_key,
+        minter: ctx.accounts.minter.key(),
+        to: ctx.accounts.to_account.key(),
+        amount,
+        daily_minted: ctx.accounts.minter_config.daily_minted,
+        total_minted: ctx.accounts.minter_config.total_minted,
+        mint_allowance: ctx.accounts.minter_config.mint_allowance,
+        confidential_supply: ctx.accounts.config.confidential_supply,
+    });
+    Ok(())
+}
+
+/// Burn from the burner's own confidential available balance (burner role or master
+/// authority). `amount` is checked against the proof's commitments like a confidential mint.
+pub fn handle_confidential_burn(
+    ctx: Context<ConfidentialBurnTokens>,
+    amount: u64,
+    amount_opening: ConfidentialAmountOpening,
+    new_decryptable_available_balance: [u8; 36],
+) -> Result<()> {
+    require!(
+        amount > 0 && amount <= MAXIMUM_DEPOSIT_TRANSFER_AMOUNT,
+        StablecoinError::InvalidAmount
+    );
+    verify_amount_opening(
+        &ctx.accounts.ciphertext_validity_proof,
+        amount,
+        &amount_opening,
+    )?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let ix = confidential_mint_burn_instruction(
+        &ctx.accounts.token_program.key(),
+        ctx.accounts.from_account.key(),
+        mint_key,
+        [
+            ctx.accounts.equality_proof.key(),
+            ctx.accounts.ciphertext_validity_proof.key(),
+            ctx.accounts.range_proof.key(),
+        ],
+        ctx.accounts.burner.key(),
+        ConfidentialMintBurnInstruction::Burn,
+        pod_bytes_of(&BurnInstructionData {
+            new_decryptable_available_balance: PodAeCiphertext::from(
+                new_decryptable_available_balance,
+            ),
+            equality_proof_instruction_offset: 0,
+            ciphertext_validity_proof_instruction_offset: 0,
+            range_proof_instruction_offset: 0,
+        }),
+    );
+    invoke(
+        &ix,
+        &[
+            ctx.accounts.from_account.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.equality_proof.to_account_info(),
+            ctx.accounts.ciphertext_validity_proof.to_account_info(),
+            ctx.accounts.range_proof.to_account_info(),
+            ctx.accounts.burner.to_account_info(),
+        ],
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.confidential_supply = config
+        .confidential_supply
+        .checked_sub(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+
+    emit_cpi!(ConfidentialBurned {
+        mint: mint_key,
+        burner: ctx.accounts.burner.key(),
+        from: ctx.accounts.from_account.key(),
+        amount,
+        confidential_supply: ctx.accounts.config.confidential_supply,
+    });
+    Ok(())
+}
+
+/// Check that `opening` opens the low/high amount commitments in the ciphertext validity proof
+/// to `amount`. The validity and range proofs tie those commitments to the minted or burned
+/// ciphertexts, so this is what binds the declared amount to the tokens that actually move.
+fn verify_amount_opening(
+    proof: &AccountInfo,
+    amount: u64,
+    opening: &ConfidentialAmountOpening,
+) -> Result<()> {
+    require_keys_eq!(
+        *proof.owner,
+        zk_elgamal_proof_program::id(),
+        StablecoinError::ConfidentialAmountMismatch
+    );
+    let data = proof.try_borrow_data()?;
+    let state = pod_from_bytes::<
+        ProofContextState<BatchedGroupedCiphertext3HandlesValidityProofContext>,
+    >(&data)
+    .map_err(|_| StablecoinError::ConfidentialAmountMismatch)?;
+    require!(
+        state.proof_type == ProofType::BatchedGroupedCiphertext3HandlesValidity.into(),
+        StablecoinError::ConfidentialAmountMismatch
+    );
+
+    let context = &state.proof_context;
+    let parts = [
+        (
+            &context.grouped_ciphertext_lo,
+            amount & ((1 << AMOUNT_LO_BITS) - 1),
+            &opening.lo,
+        ),
+        (
+            &context.grouped_ciphertext_hi,
+            amount >> AMOUNT_LO_BITS,
+            &opening.hi,
+        ),
+    ];
+    for (grouped_ciphertext, value, blinding) in parts {
+        let mut value_scalar = [0u8; 32];
+        value_scalar[..8].copy_from_slice(&value.to_le_bytes());
+        let commitment = multiscalar_multiply_ristretto(
+            &[PodScalar(value_scalar), PodScalar(*blinding)],
+            &[PEDERSEN_G, PEDERSEN_H],
+        )
+        .ok_or(StablecoinError::ConfidentialAmountMismatch)?;
+        // A grouped ciphertext starts with its Pedersen commitment, followed by the handles.
+        require!(
+            pod_bytes_of(grouped_ciphertext)[..32] == commitment.0,
+            StablecoinError::ConfidentialAmountMismatch
+        );
+    }
+    Ok(())
+}
+
+/// Token-2022 `ConfidentialMintBurn` Mint/Burn instruction reading all three proofs from
+/// context state accounts (offsets 0). The SDK builders are client-only, so it is encoded here.
+fn confidential_mint_burn_instruction(
+    token_program_id: &Pubkey,
+    token_account: Pubkey,
+    mint: Pubkey,
+    proof_accounts: [Pubkey; 3],
+    authority: Pubkey,
+    instruction_type: ConfidentialMintBurnInstruction,
+    instruction_data: &[u8],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(token_account, false),
+        AccountMeta::new(mint, false),
+    ];
+    accounts.extend(
+        proof_accounts
+            .iter()
+            .map(|proof| AccountMeta::new_readonly(*proof, false)),
+    );
+    accounts.push(AccountMeta::new_readonly(authority, true));
+
+    let mut data = TokenInstruction::ConfidentialMintBurnExtension.pack();
+    data.push(instruction_type.into());
+    data.extend_from_slice(instruction_data);
+    Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    }
+}
+
+/// Auditor key as Token-2022 expects it. All zeros is how the extension encodes "no auditor",
+/// so it is rejected rather than silently treated as `None`.
+pub(crate) fn auditor_pod_pubkey(key: Option<[u8; 32]>) -> Result<Option<PodElGamalPubkey>> {
+    match key {
+        Some(bytes) => {
+            require!(
+                bytes != [0u8; 32],
+                StablecoinError::InvalidAuditorElGamalPubkey
+            );
+            Ok(Some(PodElGamalPubkey::from(bytes)))
+        }
+        None => Ok(None),
+    }
+}
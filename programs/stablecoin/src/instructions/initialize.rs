@@ -4,20 +4,35 @@ use anchor_lang::system_program;
 use anchor_spl::{
     token_2022::spl_token_2022::{
         extension::{
+            confidential_mint_burn::instruction::initialize_mint as initialize_confidential_mint_burn,
             confidential_transfer::instruction::initialize_mint as initialize_confidential_transfer_mint,
+            default_account_state::instruction::initialize_default_account_state,
+            metadata_pointer::instruction::initialize as initialize_metadata_pointer,
             transfer_hook::instruction::initialize as initialize_transfer_hook,
             ExtensionType,
         },
         instruction::{initialize_mint2, initialize_permanent_delegate},
-        state::Mint as SplMint,
+        solana_zk_sdk::encryption::pod::{
+            auth_encryption::PodAeCiphertext, elgamal::PodElGamalPubkey,
+        },
+        state::{AccountState, Mint as SplMint},
+    },
+    token_interface::{
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+        Token2022, TokenMetadataInitialize,
     },
-    token_interface::Token2022,
 };
 
-use crate::{errors::*, state::*};
+use crate::{
+    errors::*,
+    events::*,
+    instructions::confidential::{auditor_pod_pubkey, ConfidentialMintBurnParams},
+    state::*,
+};
 
 /// Truncate string to at most `max_bytes` bytes on UTF-8 boundary.
-fn truncate_to_bytes(s: &str, max_bytes: usize) -> String {
+pub(crate) fn truncate_to_bytes(s: &str, max_bytes: usize) -> String {
     if s.len() <= max_bytes {
         return s.to_string();
     }
@@ -28,6 +43,21 @@ fn truncate_to_bytes(s: &str, max_bytes: usize) -> String {
     s[..end].to_string()
 }
 
+/// Token-2022 extensions and compliance features chosen at launch, passed to `initialize`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct InitializeParams {
+    pub enable_permanent_delegate: bool,
+    pub enable_transfer_hook: bool,
+    pub default_account_frozen: bool,
+    pub enable_confidential_transfers: bool,
+    pub enable_allowlist: bool,
+    pub transfer_hook_program_id: Option<Pubkey>, // Required when enable_transfer_hook is set
+    pub auto_approve_confidential_accounts: bool,
+    pub auditor_elgamal_pubkey: Option<[u8; 32]>, // Requires enable_confidential_transfers
+    pub confidential_mint_burn: Option<ConfidentialMintBurnParams>, // Requires enable_confidential_transfers
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(_name: String, _symbol: String)]
 pub struct Initialize<'info> {
@@ -70,14 +100,22 @@ pub fn handle_initialize(
     symbol: String,
     uri: String,
     decimals: u8,
-    enable_permanent_delegate: bool,
-    enable_transfer_hook: bool,
-    default_account_frozen: bool,
-    enable_confidential_transfers: bool,
-    enable_allowlist: bool,
-    transfer_hook_program_id: Option<Pubkey>,
+    params: InitializeParams,
 ) -> Result<()> {
-    let mut extension_types = vec![];
+    let InitializeParams {
+        enable_permanent_delegate,
+        enable_transfer_hook,
+        default_account_frozen,
+        enable_confidential_transfers,
+        enable_allowlist,
+        transfer_hook_program_id,
+        auto_approve_confidential_accounts,
+        auditor_elgamal_pubkey,
+        confidential_mint_burn,
+    } = params;
+    let enable_confidential_mint_burn = confidential_mint_burn.is_some();
+    // Metadata lives on the mint itself (MetadataPointer -> mint); config PDA is update authority.
+    let mut extension_types = vec![ExtensionType::MetadataPointer];
     if enable_permanent_delegate {
         extension_types.push(ExtensionType::PermanentDelegate);
     }
@@ -87,13 +125,44 @@ pub fn handle_initialize(
     if enable_confidential_transfers {
         extension_types.push(ExtensionType::ConfidentialTransferMint);
     }
+    if enable_confidential_mint_burn {
+        extension_types.push(ExtensionType::ConfidentialMintBurn);
+    }
+    // Compliance mints always carry DefaultAccountState so set_default_account_state can flip it later.
+    let enable_default_account_state = default_account_frozen || enable_transfer_hook;
+    if enable_default_account_state {
+        extension_types.push(ExtensionType::DefaultAccountState);
+    }
+
+    // Auditor and approval settings only exist on the ConfidentialTransferMint extension.
+    require!(
+        enable_confidential_transfers || auditor_elgamal_pubkey.is_none(),
+        StablecoinError::ConfidentialTransfersNotEnabled
+    );
+    let auditor_pod_pubkey = auditor_pod_pubkey(auditor_elgamal_pubkey)?;
+    // Token-2022 rejects ConfidentialMintBurn without ConfidentialTransferMint; fail with our error first.
+    require!(
+        enable_confidential_transfers || !enable_confidential_mint_burn,
+        StablecoinError::ConfidentialTransfersNotEnabled
+    );
 
     let mint_size = ExtensionType::try_calculate_account_len::<SplMint>(&extension_types)
         .map_err(|_| StablecoinError::MathOverflow)?;
     require!(decimals <= 18, StablecoinError::InvalidDecimals);
 
+    // TokenMetadata is variable-length: Token-2022 reallocs on initialize, so fund it up front.
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(ctx.accounts.config.key()))?,
+        mint: ctx.accounts.mint.key(),
+        name: truncate_to_bytes(&name, 64),
+        symbol: truncate_to_bytes(&symbol, 16),
+        uri: truncate_to_bytes(&uri, 256),
+        additional_metadata: vec![],
+    };
+    let metadata_space = token_metadata.tlv_size_of()?;
+
     let rent = Rent::get()?;
-    let lamports = rent.minimum_balance(mint_size);
+    let lamports = rent.minimum_balance(mint_size + metadata_space);
 
     let mint_bump = ctx.bumps.mint;
     let symbol_bytes = symbol.as_bytes();
@@ -114,6 +183,16 @@ pub fn handle_initialize(
             ctx.accounts.token_program.key,
         )?;
 
+        invoke(
+            &initialize_metadata_pointer(
+                ctx.accounts.token_program.key,
+                ctx.accounts.mint.key,
+                Some(ctx.accounts.config.key()),
+                Some(ctx.accounts.mint.key()),
+            )?,
+            &[ctx.accounts.mint.to_account_info()],
+        )?;
+
         if enable_permanent_delegate {
             invoke(
                 &initialize_permanent_delegate(
@@ -150,8 +229,8 @@ pub fn handle_initialize(
                 ctx.accounts.token_program.key,
                 ctx.accounts.mint.key,
                 Some(ctx.accounts.config.key()),
-                true, // auto_approve_new_accounts
-                None, // auditor_elgamal_pubkey
+                auto_approve_confidential_accounts,
+                auditor_pod_pubkey,
             )
             .map_err(|_| StablecoinError::ConfidentialTransfersNotEnabled)?;
             invoke(
@@ -160,6 +239,34 @@ pub fn handle_initialize(
             )?;
         }
 
+        if let Some(params) = &confidential_mint_burn {
+            invoke(
+                &initialize_confidential_mint_burn(
+                    ctx.accounts.token_program.key,
+                    ctx.accounts.mint.key,
+                    PodElGamalPubkey::from(params.supply_elgamal_pubkey),
+                    PodAeCiphertext::from(params.decryptable_supply),
+                )?,
+                &[ctx.accounts.mint.to_account_info()],
+            )?;
+        }
+
+        if enable_default_account_state {
+            let state = if default_account_frozen {
+                AccountState::Frozen
+            } else {
+                AccountState::Initialized
+            };
+            invoke(
+                &initialize_default_account_state(
+                    ctx.accounts.token_program.key,
+                    ctx.accounts.mint.key,
+                    &state,
+                )?,
+                &[ctx.accounts.mint.to_account_info()],
+            )?;
+        }
+
         invoke(
             &initialize_mint2(
                 ctx.accounts.token_program.key,
@@ -173,22 +280,54 @@ pub fn handle_initialize(
                 ctx.accounts.config.to_account_info(),
             ],
         )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let config_seeds: &[&[&[u8]]] = &[&[
+            StablecoinConfig::SEED_PREFIX,
+            mint_key.as_ref(),
+            &[ctx.bumps.config],
+        ]];
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint.to_account_info(),
+                    update_authority: ctx.accounts.config.to_account_info(),
+                    mint_authority: ctx.accounts.config.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+                config_seeds,
+            ),
+            token_metadata.name.clone(),
+            token_metadata.symbol.clone(),
+            token_metadata.uri.clone(),
+        )?;
     }
 
     let config = &mut ctx.accounts.config;
     config.bump = ctx.bumps.config;
     config.master_authority = ctx.accounts.admin.key();
     config.mint = ctx.accounts.mint.key();
-    config.name = truncate_to_bytes(&name, 64);
-    config.symbol = truncate_to_bytes(&symbol, 16);
-    config.uri = truncate_to_bytes(&uri, 256);
+    config.name = token_metadata.name;
+    config.symbol = token_metadata.symbol;
+    config.uri = token_metadata.uri;
     config.decimals = decimals;
-    config.is_paused = false;
+    config.pause_flags = 0;
     config.enable_permanent_delegate = enable_permanent_delegate;
     config.enable_transfer_hook = enable_transfer_hook;
     config.default_account_frozen = default_account_frozen;
     config.enable_confidential_transfers = enable_confidential_transfers;
     config.enable_allowlist = enable_allowlist;
+    config.pending_authority = None;
+    config.max_supply = None;
+    config.pause_reason = PauseReason::Unspecified;
+    config.paused_at = 0;
+    config.transfer_limit_tiers = [TransferLimitTier::default(); TRANSFER_LIMIT_TIERS];
+    config.travel_rule_threshold = None;
+    config.enable_confidential_mint_burn = enable_confidential_mint_burn;
+    config.confidential_supply = 0;
+    config.oracle_feed = None;
 
     let roles = &mut ctx.accounts.role_account;
     roles.bump = ctx.bumps.role_account;
@@ -196,6 +335,22 @@ pub fn handle_initialize(
     roles.pauser = ctx.accounts.admin.key();
     roles.blacklister = ctx.accounts.admin.key();
     roles.seizer = ctx.accounts.admin.key();
+    roles.minter_controller = ctx.accounts.admin.key();
+    roles.allowlister = ctx.accounts.admin.key();
+
+    emit_cpi!(StablecoinInitialized {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        decimals,
+        enable_permanent_delegate,
+        enable_transfer_hook,
+        default_account_frozen,
+        enable_confidential_transfers,
+        enable_allowlist,
+        auto_approve_confidential_accounts,
+        auditor_elgamal_pubkey,
+        enable_confidential_mint_burn,
+    });
 
     Ok(())
 }
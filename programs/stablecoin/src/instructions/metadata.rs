@@ -0,0 +1,132 @@
+use crate::{errors::*, events::*, instructions::initialize::truncate_to_bytes, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+    token_metadata_update_field, Mint, Token2022, TokenMetadataUpdateField,
+};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as SplMint,
+};
+
+/// One additional key/value pair stored in the mint's TokenMetadata.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MetadataField {
+    pub key: String,
+    pub value: String,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Update name, uri and additional fields in the mint's TokenMetadata and mirror name/uri
+/// into `StablecoinConfig`. Tops up the mint's rent first, since Token-2022 reallocs in place.
+pub fn handle_update_metadata(
+    ctx: Context<UpdateMetadata>,
+    name: Option<String>,
+    uri: Option<String>,
+    additional_metadata: Vec<MetadataField>,
+) -> Result<()> {
+    let mut updates: Vec<(Field, String)> = vec![];
+    if let Some(name) = name {
+        updates.push((Field::Name, truncate_to_bytes(&name, 64)));
+    }
+    if let Some(uri) = uri {
+        updates.push((Field::Uri, truncate_to_bytes(&uri, 256)));
+    }
+    for field in additional_metadata {
+        updates.push((Field::Key(field.key), field.value));
+    }
+
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let new_len = {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+        let mut metadata = mint_state.get_variable_len_extension::<TokenMetadata>()?;
+        let old_tlv = metadata.tlv_size_of()?;
+        for (field, value) in &updates {
+            metadata.update(field.clone(), value.clone());
+        }
+        let new_tlv = metadata.tlv_size_of()?;
+        mint_data
+            .len()
+            .checked_sub(old_tlv)
+            .and_then(|len| len.checked_add(new_tlv))
+            .ok_or(StablecoinError::MathOverflow)?
+    };
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(mint_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    let bump = ctx.accounts.config.bump;
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    for (field, value) in &updates {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    update_authority: ctx.accounts.config.to_account_info(),
+                },
+                signer,
+            ),
+            field.clone(),
+            value.clone(),
+        )?;
+    }
+
+    let config = &mut ctx.accounts.config;
+    for (field, value) in updates {
+        match field {
+            Field::Name => config.name = value,
+            Field::Uri => config.uri = value,
+            _ => {}
+        }
+    }
+
+    emit_cpi!(MetadataUpdated {
+        mint: mint_key,
+        authority: ctx.accounts.admin.key(),
+        name: ctx.accounts.config.name.clone(),
+        uri: ctx.accounts.config.uri.clone(),
+    });
+
+    Ok(())
+}
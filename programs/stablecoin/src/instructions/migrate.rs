@@ -0,0 +1,352 @@
+use crate::{errors::*, events::*, state::*};
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAccounts<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: May still be in a legacy layout, so it is not deserialized here. Seeds and owner
+    /// are checked by Anchor; discriminator and master authority are checked in the handler.
+    #[account(
+        mut,
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    /// CHECK: Same as config; the legacy layout is decoded in the handler.
+    #[account(
+        mut,
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub roles: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Bring a deployment's config and roles PDAs up to the current layout. Safe to call again:
+/// accounts already at the current size are left untouched.
+///
+/// - `StablecoinConfig` only gained trailing fields and was allocated at max string length, so
+///   growing it with zeroed bytes decodes them as unset. The one in-place change is the
+///   `is_paused` bool becoming `pause_flags`: a legacy `true` is rewritten as mint + burn
+///   paused, which is what it used to block. Only the first-deployed size is accepted.
+/// - `RoleAccount` is rewritten from [`RoleAccountV0`]; roles added since (minter controller,
+///   allowlister) default to the master authority, with no pending proposals.
+pub fn handle_migrate_accounts(ctx: Context<MigrateAccounts>) -> Result<()> {
+    let admin = ctx.accounts.admin.to_account_info();
+    let system = ctx.accounts.system_program.to_account_info();
+    let config_info = ctx.accounts.config.to_account_info();
+    let master_authority = {
+        let data = config_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 + 1 + 32 && data[..8] == *StablecoinConfig::DISCRIMINATOR,
+            StablecoinError::UnknownAccountLayout
+        );
+        // bump (1) precedes master_authority in every layout.
+        Pubkey::try_from(&data[9..41]).map_err(|_| StablecoinError::UnknownAccountLayout)?
+    };
+    require!(
+        master_authority == ctx.accounts.admin.key(),
+        StablecoinError::Unauthorized
+    );
+
+    let config_len = 8 + StablecoinConfig::INIT_SPACE;
+    let config_migrated = match config_info.data_len() {
+        len if len == config_len => false,
+        CONFIG_V0_LEN => true,
+        _ => return err!(StablecoinError::UnknownAccountLayout),
+    };
+    if config_migrated {
+        grow_account(&admin, &config_info, &system, config_len)?;
+        let mut config =
+            StablecoinConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+        if config.pause_flags != 0 {
+            config.pause_flags = StablecoinConfig::PAUSE_MINT | StablecoinConfig::PAUSE_BURN;
+            config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+        }
+    }
+
+    let roles_info = ctx.accounts.roles.to_account_info();
+    let roles_len = 8 + RoleAccount::INIT_SPACE;
+    let roles_migrated = match roles_info.data_len() {
+        len if len == roles_len => false,
+        RoleAccountV0::LEN => {
+            let legacy = {
+                let data = roles_info.try_borrow_data()?;
+                require!(
+                    data[..8] == *RoleAccount::DISCRIMINATOR,
+                    StablecoinError::UnknownAccountLayout
+                );
+                RoleAccountV0::deserialize(&mut &data[8..])?
+            };
+            grow_account(&admin, &roles_info, &system, roles_len)?;
+            let roles = RoleAccount {
+                bump: legacy.bump,
+                burner: legacy.burner,
+                pauser: legacy.pauser,
+                blacklister: legacy.blacklister,
+                seizer: legacy.seizer,
+                minter_controller: master_authority,
+                allowlister: master_authority,
+                pending_burner: None,
+                pending_pauser: None,
+                pending_blacklister: None,
+                pending_seizer: None,
+                pending_minter_controller: None,
+                pending_allowlister: None,
+            };
+            let mut data = roles_info.try_borrow_mut_data()?;
+            roles.try_serialize(&mut &mut data[..])?;
+            true
+        }
+        _ => return err!(StablecoinError::UnknownAccountLayout),
+    };
+
+    emit_cpi!(AccountsMigrated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        config_migrated,
+        roles_migrated,
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateBlacklistEntry<'info> {
+    #[account(mut)]
+    pub blacklister: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.blacklister == blacklister.key() || config.master_authority == blacklister.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    /// CHECK: The blacklisted account; only its pubkey is used for the PDA.
+    pub target_account: UncheckedAccount<'info>,
+
+    /// CHECK: May still be in the legacy layout; seeds and owner checked by Anchor.
+    #[account(
+        mut,
+        seeds = [BlacklistEntry::SEED_PREFIX, mint.key().as_ref(), target_account.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a legacy `BlacklistEntry` to the current layout so it can be updated again. The
+/// appended fields read as reason `Unspecified`, no case reference, unknown listing time and
+/// no expiry. No-op if the entry is already current.
+pub fn handle_migrate_blacklist_entry(ctx: Context<MigrateBlacklistEntry>) -> Result<()> {
+    let entry_info = ctx.accounts.blacklist_entry.to_account_info();
+    let entry_len = 8 + BlacklistEntry::INIT_SPACE;
+    if !is_shorter_layout(&entry_info, BlacklistEntry::DISCRIMINATOR, entry_len)? {
+        return Ok(());
+    }
+    grow_account(
+        &ctx.accounts.blacklister.to_account_info(),
+        &entry_info,
+        &ctx.accounts.system_program.to_account_info(),
+        entry_len,
+    )?;
+
+    emit_cpi!(BlacklistEntryMigrated {
+        mint: ctx.accounts.mint.key(),
+        blacklister: ctx.accounts.blacklister.key(),
+        account: ctx.accounts.target_account.key(),
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAllowlistEntry<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [RoleAccount::SEED_PREFIX, mint.key().as_ref()],
+        bump = roles.bump,
+        constraint = roles.allowlister == authority.key() || config.master_authority == authority.key() @ StablecoinError::Unauthorized
+    )]
+    pub roles: Account<'info, RoleAccount>,
+
+    /// CHECK: The allowlisted wallet; only its pubkey is used for the PDA.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// CHECK: May still be in the legacy layout; seeds and owner checked by Anchor.
+    #[account(
+        mut,
+        seeds = [AllowlistEntry::SEED_PREFIX, mint.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a legacy `AllowlistEntry` (no expiry and/or no tier) to the current layout; the appended
+/// fields read as no expiry and tier 0. No-op if already current.
+pub fn handle_migrate_allowlist_entry(ctx: Context<MigrateAllowlistEntry>) -> Result<()> {
+    let entry_info = ctx.accounts.allowlist_entry.to_account_info();
+    let entry_len = 8 + AllowlistEntry::INIT_SPACE;
+    if !is_shorter_layout(&entry_info, AllowlistEntry::DISCRIMINATOR, entry_len)? {
+        return Ok(());
+    }
+    grow_account(
+        &ctx.accounts.authority.to_account_info(),
+        &entry_info,
+        &ctx.accounts.system_program.to_account_info(),
+        entry_len,
+    )?;
+
+    emit_cpi!(AllowlistEntryMigrated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        wallet: ctx.accounts.wallet.key(),
+    });
+    Ok(())
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateMinterConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = config.master_authority == admin.key() @ StablecoinError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: The minter; only its pubkey is used for the PDA.
+    pub minter: UncheckedAccount<'info>,
+
+    /// CHECK: May still be in the legacy layout; seeds and owner checked by Anchor, the
+    /// layout in the handler.
+    #[account(
+        mut,
+        seeds = [MinterConfig::SEED_PREFIX, mint.key().as_ref(), minter.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub minter_config: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite a legacy minter config so it can mint again. Unlike the entry layouts, zero padding
+/// alone is not enough: a zero `window_seconds` would give a 24-second window, so every field
+/// is written explicitly.
+///
+/// The only legacy layout is [`MinterConfigV0`] (first deployed), which moves onto the default
+/// rolling window in `RollingWindow` mode with no allowance; see [`MinterConfig::from_v0`].
+///
+/// Run after `migrate_accounts`, which brings the config it reads up to date. No-op if
+/// already current.
+pub fn handle_migrate_minter_config(ctx: Context<MigrateMinterConfig>) -> Result<()> {
+    let info = ctx.accounts.minter_config.to_account_info();
+    let current_len = 8 + MinterConfig::INIT_SPACE;
+    let minter_config = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.starts_with(MinterConfig::DISCRIMINATOR),
+            StablecoinError::UnknownAccountLayout
+        );
+        match data.len() {
+            len if len == current_len => return Ok(()),
+            MinterConfigV0::LEN => MinterConfig::from_v0(
+                MinterConfigV0::deserialize(&mut &data[8..])?,
+                Clock::get()?.unix_timestamp,
+            ),
+            _ => return err!(StablecoinError::UnknownAccountLayout),
+        }
+    };
+    grow_account(
+        &ctx.accounts.admin.to_account_info(),
+        &info,
+        &ctx.accounts.system_program.to_account_info(),
+        current_len,
+    )?;
+    minter_config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(MinterConfigMigrated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.admin.key(),
+        minter: ctx.accounts.minter.key(),
+    });
+    Ok(())
+}
+
+/// Allocated size of `StablecoinConfig` as first deployed: strings at max length, then
+/// decimals, the `is_paused` bool that `pause_flags` replaced, and five feature flags.
+const CONFIG_V0_LEN: usize = 8 + 1 + 32 + 32 + (4 + 64) + (4 + 16) + (4 + 256) + 1 + 1 + 5;
+
+/// Whether `info` holds an earlier layout of the account type with `discriminator`. Entry
+/// layouts only ever gain trailing fields that read as unset when zeroed, so any shorter
+/// length is a valid prefix; longer data or a different discriminator is rejected.
+fn is_shorter_layout(info: &AccountInfo, discriminator: &[u8], current_len: usize) -> Result<bool> {
+    let data = info.try_borrow_data()?;
+    require!(
+        data.starts_with(discriminator) && data.len() <= current_len,
+        StablecoinError::UnknownAccountLayout
+    );
+    Ok(data.len() < current_len)
+}
+
+/// Top up rent from `payer` and realloc `info` to `new_len` (new bytes zeroed).
+fn grow_account<'info>(
+    payer: &AccountInfo<'info>,
+    info: &AccountInfo<'info>,
+    system: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let shortfall = required.saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    info.realloc(new_len, true)?;
+    Ok(())
+}
@@ -1,7 +1,10 @@
-use crate::{errors::*, state::*};
+use crate::{errors::*, events::*, state::*};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::get_return_data, sysvar};
 use anchor_spl::token_interface::{mint_to, Mint, MintTo, Token2022, TokenAccount};
+use oracle::{cpi::accounts::ReadQuote, program::Oracle};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MintTokens<'info> {
     #[account(mut)]
@@ -10,7 +13,7 @@ pub struct MintTokens<'info> {
     #[account(
         seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
         bump = config.bump,
-        constraint = !config.is_paused @ StablecoinError::ProgramPaused
+        constraint = !config.is_paused(StablecoinConfig::PAUSE_MINT) @ StablecoinError::ProgramPaused
     )]
     pub config: Account<'info, StablecoinConfig>,
 
@@ -37,32 +40,71 @@ pub struct MintTokens<'info> {
     pub token_program: Program<'info, Token2022>,
 }
 
-pub fn handle_mint(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
-    require!(amount > 0, StablecoinError::InvalidAmount);
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintAtOraclePrice<'info> {
+    #[account(mut)]
+    pub minter: Signer<'info>,
 
-    let config = &mut ctx.accounts.minter_config;
-    let current_time = Clock::get()?.unix_timestamp;
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        constraint = !config.is_paused(StablecoinConfig::PAUSE_MINT) @ StablecoinError::ProgramPaused,
+        constraint = config.oracle_feed.is_some() @ StablecoinError::OracleFeedNotSet
+    )]
+    pub config: Account<'info, StablecoinConfig>,
 
-    // Reset quota if 24 hours have passed
-    if current_time >= config.last_mint_timestamp + 86400 {
-        config.daily_minted = 0;
-        config.last_mint_timestamp = current_time;
-    }
+    #[account(
+        mut,
+        seeds = [MinterConfig::SEED_PREFIX, mint.key().as_ref(), minter.key().as_ref()],
+        bump = minter_config.bump,
+        constraint = minter_config.is_active @ StablecoinError::MinterInactive
+    )]
+    pub minter_config: Account<'info, MinterConfig>,
 
-    let new_daily = config
-        .daily_minted
-        .checked_add(amount)
-        .ok_or(StablecoinError::MathOverflow)?;
-    if new_daily > config.daily_mint_quota {
-        return err!(StablecoinError::QuotaExceeded);
-    }
-    config.daily_minted = new_daily;
-    config.total_minted = config
-        .total_minted
-        .checked_add(amount)
-        .ok_or(StablecoinError::MathOverflow)?;
+    #[account(
+        mut,
+        address = config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
 
-    let mint_key = ctx.accounts.mint.key().clone();
+    #[account(
+        mut,
+        constraint = to_account.mint == mint.key()
+    )]
+    pub to_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Switchboard queue for the quote, pinned in `config.oracle_feed`; the oracle
+    /// program checks the quote's signing keys against it.
+    #[account(
+        constraint = config.oracle_feed.is_some_and(|feed| feed.queue == queue.key()) @ StablecoinError::InvalidOracleQueue
+    )]
+    pub queue: UncheckedAccount<'info>,
+
+    /// CHECK: SlotHashes sysvar, read by the oracle's quote verifier.
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar; the Switchboard Ed25519 verify ix must be at index 1.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub oracle_program: Program<'info, Oracle>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handle_mint(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+    require!(amount > 0, StablecoinError::InvalidAmount);
+    // Token-2022 rejects MintTo on a ConfidentialMintBurn mint; fail before charging the quota.
+    require!(
+        !ctx.accounts.config.enable_confidential_mint_burn,
+        StablecoinError::PublicMintDisabled
+    );
+
+    check_supply_cap(&ctx.accounts.config, &ctx.accounts.mint, amount)?;
+    charge_minter_quota(&mut ctx.accounts.minter_config, amount)?;
+
+    let mint_key = ctx.accounts.mint.key();
     let mint_key = mint_key.as_ref();
     let bump = ctx.accounts.config.bump;
     let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key, &[bump]];
@@ -78,5 +120,159 @@ pub fn handle_mint(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     mint_to(cpi_ctx, amount)?;
 
+    // Mint is not reloaded after the CPI, so `supply` still holds the pre-CPI value.
+    let supply_before = ctx.accounts.mint.supply;
+    emit_cpi!(Minted {
+        mint: ctx.accounts.mint.key(),
+        minter: ctx.accounts.minter.key(),
+        to: ctx.accounts.to_account.key(),
+        amount,
+        daily_minted: ctx.accounts.minter_config.daily_minted,
+        total_minted: ctx.accounts.minter_config.total_minted,
+        mint_allowance: ctx.accounts.minter_config.mint_allowance,
+        supply_before,
+        supply_after: supply_before
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?,
+    });
+
+    Ok(())
+}
+
+/// Mint the token amount the oracle program prices `peg_amount` at, so non-USD pegs never rely
+/// on a client-supplied amount. The quote must come from the queue and feed pinned in
+/// `config.oracle_feed`. Same quota, supply cap and pause checks as `mint`.
+pub fn handle_mint_at_oracle_price(ctx: Context<MintAtOraclePrice>, peg_amount: u64) -> Result<()> {
+    require!(peg_amount > 0, StablecoinError::InvalidAmount);
+    require!(
+        !ctx.accounts.config.enable_confidential_mint_burn,
+        StablecoinError::PublicMintDisabled
+    );
+    let feed = ctx
+        .accounts
+        .config
+        .oracle_feed
+        .ok_or(StablecoinError::OracleFeedNotSet)?;
+
+    oracle::cpi::compute_mint_amount_for_feed(
+        CpiContext::new(
+            ctx.accounts.oracle_program.to_account_info(),
+            ReadQuote {
+                queue: ctx.accounts.queue.to_account_info(),
+                slot_hashes: ctx.accounts.slot_hashes.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+            },
+        ),
+        peg_amount,
+        ctx.accounts.config.decimals,
+        feed.feed_id,
+    )?;
+    let (program_id, data) = get_return_data().ok_or(StablecoinError::InvalidOracleReturnData)?;
+    require_keys_eq!(
+        program_id,
+        oracle::ID,
+        StablecoinError::InvalidOracleReturnData
+    );
+    let amount = u64::from_le_bytes(
+        data.try_into()
+            .map_err(|_| StablecoinError::InvalidOracleReturnData)?,
+    );
+    // A price high enough to round the peg amount down to zero tokens mints nothing.
+    require!(amount > 0, StablecoinError::InvalidAmount);
+
+    check_supply_cap(&ctx.accounts.config, &ctx.accounts.mint, amount)?;
+    charge_minter_quota(&mut ctx.accounts.minter_config, amount)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let bump = ctx.accounts.config.bump;
+    let seeds = &[StablecoinConfig::SEED_PREFIX, mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.to_account.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    // Mint is not reloaded after the CPI, so `supply` still holds the pre-CPI value.
+    let supply_before = ctx.accounts.mint.supply;
+    emit_cpi!(Minted {
+        mint: mint_key,
+        minter: ctx.accounts.minter.key(),
+        to: ctx.accounts.to_account.key(),
+        amount,
+        daily_minted: ctx.accounts.minter_config.daily_minted,
+        total_minted: ctx.accounts.minter_config.total_minted,
+        mint_allowance: ctx.accounts.minter_config.mint_allowance,
+        supply_before,
+        supply_after: supply_before
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?,
+    });
+    emit_cpi!(MintedAtOraclePrice {
+        mint: mint_key,
+        minter: ctx.accounts.minter.key(),
+        to: ctx.accounts.to_account.key(),
+        peg_amount,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Reject a mint that would push supply past `config.max_supply`, when a cap is set. Supply is
+/// the public `mint.supply` plus the confidential supply tracked on the config.
+pub(crate) fn check_supply_cap(config: &StablecoinConfig, mint: &Mint, amount: u64) -> Result<()> {
+    if let Some(max_supply) = config.max_supply {
+        let new_supply = mint
+            .supply
+            .checked_add(config.confidential_supply)
+            .and_then(|supply| supply.checked_add(amount))
+            .ok_or(StablecoinError::MathOverflow)?;
+        require!(new_supply <= max_supply, StablecoinError::SupplyCapExceeded);
+    }
+    Ok(())
+}
+
+/// Charge `amount` against the minter's rolling-window quota or allowance and bump its totals.
+/// Shared by public and confidential mints so both draw from the same budget.
+pub(crate) fn charge_minter_quota(config: &mut MinterConfig, amount: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    match config.quota_mode {
+        MintQuotaMode::RollingWindow => {
+            // Rolling window: drop buckets older than window_seconds, then check the remaining sum.
+            config.roll_window(current_time);
+
+            let new_daily = config
+                .daily_minted
+                .checked_add(amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+            if new_daily > config.daily_mint_quota {
+                return err!(StablecoinError::QuotaExceeded);
+            }
+            config
+                .record_mint(amount)
+                .ok_or(StablecoinError::MathOverflow)?;
+        }
+        MintQuotaMode::Allowance => {
+            config.mint_allowance = config
+                .mint_allowance
+                .checked_sub(amount)
+                .ok_or(StablecoinError::AllowanceExceeded)?;
+        }
+    }
+    config.last_mint_timestamp = current_time;
+    config.total_minted = config
+        .total_minted
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
     Ok(())
 }
@@ -2,12 +2,18 @@ pub mod admin;
 pub mod allowlist;
 pub mod burn;
 pub mod compliance;
+pub mod confidential;
 pub mod initialize;
+pub mod metadata;
+pub mod migrate;
 pub mod mint;
 
 pub use admin::*;
 pub use allowlist::*;
 pub use burn::*;
 pub use compliance::*;
+pub use confidential::*;
 pub use initialize::*;
+pub use metadata::*;
+pub use migrate::*;
 pub use mint::*;
@@ -6,10 +6,12 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::{AuthorityRole, BlacklistReason, MintQuotaMode, OracleFeed, PauseReason};
 
 declare_id!("3zFReCtrBsjMZNabaV4vJSaCHtTpFtApkWMjrr5gAeeM");
 
@@ -23,46 +25,89 @@ pub mod stablecoin {
         symbol: String,
         uri: String,
         decimals: u8,
-        enable_permanent_delegate: bool,
-        enable_transfer_hook: bool,
-        default_account_frozen: bool,
-        enable_confidential_transfers: bool,
-        enable_allowlist: bool,
-        transfer_hook_program_id: Option<Pubkey>,
-    ) -> Result<()> {
-        handle_initialize(
-            ctx,
-            name,
-            symbol,
-            uri,
-            decimals,
-            enable_permanent_delegate,
-            enable_transfer_hook,
-            default_account_frozen,
-            enable_confidential_transfers,
-            enable_allowlist,
-            transfer_hook_program_id,
-        )
+        params: InitializeParams,
+    ) -> Result<()> {
+        handle_initialize(ctx, name, symbol, uri, decimals, params)
+    }
+
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: Option<String>,
+        uri: Option<String>,
+        additional_metadata: Vec<MetadataField>,
+    ) -> Result<()> {
+        handle_update_metadata(ctx, name, uri, additional_metadata)
     }
 
     pub fn mint(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
         handle_mint(ctx, amount)
     }
 
+    pub fn mint_at_oracle_price(ctx: Context<MintAtOraclePrice>, peg_amount: u64) -> Result<()> {
+        handle_mint_at_oracle_price(ctx, peg_amount)
+    }
+
     pub fn burn(ctx: Context<BurnTokens>, amount: u64) -> Result<()> {
         handle_burn(ctx, amount)
     }
 
-    pub fn pause(ctx: Context<PauseUnpause>) -> Result<()> {
-        handle_pause(ctx)
+    pub fn pause(ctx: Context<PauseUnpause>, flags: u8, reason: PauseReason) -> Result<()> {
+        handle_pause(ctx, flags, reason)
+    }
+
+    pub fn unpause(ctx: Context<PauseUnpause>, flags: u8) -> Result<()> {
+        handle_unpause(ctx, flags)
+    }
+
+    pub fn migrate_accounts(ctx: Context<MigrateAccounts>) -> Result<()> {
+        handle_migrate_accounts(ctx)
+    }
+
+    pub fn migrate_minter_config(ctx: Context<MigrateMinterConfig>) -> Result<()> {
+        handle_migrate_minter_config(ctx)
+    }
+
+    pub fn set_max_supply(ctx: Context<UpdateConfig>, max_supply: Option<u64>) -> Result<()> {
+        handle_set_max_supply(ctx, max_supply)
+    }
+
+    pub fn set_travel_rule_threshold(
+        ctx: Context<UpdateConfig>,
+        threshold: Option<u64>,
+    ) -> Result<()> {
+        handle_set_travel_rule_threshold(ctx, threshold)
+    }
+
+    pub fn set_oracle_feed(ctx: Context<UpdateConfig>, feed: Option<OracleFeed>) -> Result<()> {
+        handle_set_oracle_feed(ctx, feed)
+    }
+
+    pub fn set_transfer_limit_tier(
+        ctx: Context<UpdateConfig>,
+        tier: u8,
+        daily_limit: u64,
+        per_transfer_limit: u64,
+    ) -> Result<()> {
+        handle_set_transfer_limit_tier(ctx, tier, daily_limit, per_transfer_limit)
+    }
+
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        role: AuthorityRole,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        handle_propose_authority(ctx, role, new_authority)
     }
 
-    pub fn unpause(ctx: Context<PauseUnpause>) -> Result<()> {
-        handle_unpause(ctx)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>, role: AuthorityRole) -> Result<()> {
+        handle_accept_authority(ctx, role)
     }
 
-    pub fn transfer_authority(ctx: Context<UpdateConfig>, new_authority: Pubkey) -> Result<()> {
-        handle_transfer_authority(ctx, new_authority)
+    pub fn cancel_authority_transfer(
+        ctx: Context<ProposeAuthority>,
+        role: AuthorityRole,
+    ) -> Result<()> {
+        handle_cancel_authority_transfer(ctx, role)
     }
 
     pub fn update_roles(
@@ -71,24 +116,56 @@ pub mod stablecoin {
         pauser: Option<Pubkey>,
         blacklister: Option<Pubkey>,
         seizer: Option<Pubkey>,
+        minter_controller: Option<Pubkey>,
+        allowlister: Option<Pubkey>,
     ) -> Result<()> {
-        handle_update_roles(ctx, burner, pauser, blacklister, seizer)
+        handle_update_roles(
+            ctx,
+            burner,
+            pauser,
+            blacklister,
+            seizer,
+            minter_controller,
+            allowlister,
+        )
     }
 
     pub fn configure_minter(
         ctx: Context<ConfigureMinter>,
         is_active: bool,
         daily_mint_quota: u64,
+        window_seconds: Option<u32>,
+        quota_mode: MintQuotaMode,
     ) -> Result<()> {
-        handle_configure_minter(ctx, is_active, daily_mint_quota)
+        handle_configure_minter(ctx, is_active, daily_mint_quota, window_seconds, quota_mode)
     }
 
     pub fn update_minter(
         ctx: Context<UpdateMinter>,
         is_active: bool,
         daily_mint_quota: u64,
+        window_seconds: Option<u32>,
+        quota_mode: Option<MintQuotaMode>,
+    ) -> Result<()> {
+        handle_update_minter(ctx, is_active, daily_mint_quota, window_seconds, quota_mode)
+    }
+
+    pub fn remove_minter(ctx: Context<RemoveMinter>) -> Result<()> {
+        handle_remove_minter(ctx)
+    }
+
+    pub fn increase_minter_allowance(
+        ctx: Context<AdjustMinterAllowance>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_increase_minter_allowance(ctx, amount)
+    }
+
+    pub fn decrease_minter_allowance(
+        ctx: Context<AdjustMinterAllowance>,
+        amount: u64,
     ) -> Result<()> {
-        handle_update_minter(ctx, is_active, daily_mint_quota)
+        handle_decrease_minter_allowance(ctx, amount)
     }
 
     pub fn freeze_account(ctx: Context<FreezeThaw>) -> Result<()> {
@@ -99,31 +176,133 @@ pub mod stablecoin {
         handle_thaw_account(ctx)
     }
 
-    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>) -> Result<()> {
-        handle_add_to_blacklist(ctx)
+    pub fn set_default_account_state(
+        ctx: Context<SetDefaultAccountState>,
+        frozen: bool,
+    ) -> Result<()> {
+        handle_set_default_account_state(ctx, frozen)
+    }
+
+    pub fn add_to_blacklist(
+        ctx: Context<AddToBlacklist>,
+        reason: BlacklistReason,
+        case_reference: [u8; 32],
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        handle_add_to_blacklist(ctx, reason, case_reference, expires_at)
     }
 
     pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>) -> Result<()> {
         handle_remove_from_blacklist(ctx)
     }
 
-    pub fn update_blacklist_entry(ctx: Context<UpdateBlacklistEntry>, is_blacklisted: bool) -> Result<()> {
-        handle_update_blacklist_entry(ctx, is_blacklisted)
+    pub fn update_blacklist_entry(
+        ctx: Context<UpdateBlacklistEntry>,
+        is_blacklisted: bool,
+        reason: BlacklistReason,
+        case_reference: [u8; 32],
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        handle_update_blacklist_entry(ctx, is_blacklisted, reason, case_reference, expires_at)
+    }
+
+    pub fn migrate_blacklist_entry(ctx: Context<MigrateBlacklistEntry>) -> Result<()> {
+        handle_migrate_blacklist_entry(ctx)
+    }
+
+    pub fn close_blacklist_entry(ctx: Context<CloseBlacklistEntry>) -> Result<()> {
+        handle_close_blacklist_entry(ctx)
     }
 
     pub fn seize(ctx: Context<Seize>, amount: u64) -> Result<()> {
         handle_seize(ctx, amount)
     }
 
-    pub fn add_to_allowlist(ctx: Context<AddToAllowlist>) -> Result<()> {
-        handle_add_to_allowlist(ctx)
+    pub fn add_to_allowlist(
+        ctx: Context<AddToAllowlist>,
+        expires_at: Option<i64>,
+        tier: u8,
+    ) -> Result<()> {
+        handle_add_to_allowlist(ctx, expires_at, tier)
     }
 
     pub fn remove_from_allowlist(ctx: Context<RemoveFromAllowlist>) -> Result<()> {
         handle_remove_from_allowlist(ctx)
     }
 
-    pub fn update_allowlist_entry(ctx: Context<UpdateAllowlistEntry>, is_allowed: bool) -> Result<()> {
-        handle_update_allowlist_entry(ctx, is_allowed)
+    pub fn update_allowlist_entry(
+        ctx: Context<UpdateAllowlistEntry>,
+        is_allowed: bool,
+        expires_at: Option<i64>,
+        tier: u8,
+    ) -> Result<()> {
+        handle_update_allowlist_entry(ctx, is_allowed, expires_at, tier)
+    }
+
+    pub fn migrate_allowlist_entry(ctx: Context<MigrateAllowlistEntry>) -> Result<()> {
+        handle_migrate_allowlist_entry(ctx)
+    }
+
+    pub fn initialize_allowlist_policy(ctx: Context<InitializeAllowlistPolicy>) -> Result<()> {
+        handle_initialize_allowlist_policy(ctx)
+    }
+
+    pub fn set_allowlist_tier(
+        ctx: Context<SetAllowlistTier>,
+        tier: u8,
+        max_transfer_amount: u64,
+        allowed_recipient_tiers: u8,
+    ) -> Result<()> {
+        handle_set_allowlist_tier(ctx, tier, max_transfer_amount, allowed_recipient_tiers)
+    }
+
+    pub fn close_allowlist_entry(ctx: Context<CloseAllowlistEntry>) -> Result<()> {
+        handle_close_allowlist_entry(ctx)
+    }
+
+    pub fn set_allowlist_enabled(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        handle_set_allowlist_enabled(ctx, enabled)
+    }
+
+    pub fn approve_confidential_account(ctx: Context<ApproveConfidentialAccount>) -> Result<()> {
+        handle_approve_confidential_account(ctx)
+    }
+
+    pub fn update_confidential_mint(
+        ctx: Context<UpdateConfidentialMint>,
+        auto_approve_new_accounts: bool,
+        auditor_elgamal_pubkey: Option<[u8; 32]>,
+    ) -> Result<()> {
+        handle_update_confidential_mint(ctx, auto_approve_new_accounts, auditor_elgamal_pubkey)
+    }
+
+    pub fn confidential_mint(
+        ctx: Context<ConfidentialMintTokens>,
+        amount: u64,
+        amount_opening: ConfidentialAmountOpening,
+        new_decryptable_supply: [u8; 36],
+    ) -> Result<()> {
+        handle_confidential_mint(ctx, amount, amount_opening, new_decryptable_supply)
+    }
+
+    pub fn confidential_burn(
+        ctx: Context<ConfidentialBurnTokens>,
+        amount: u64,
+        amount_opening: ConfidentialAmountOpening,
+        new_decryptable_available_balance: [u8; 36],
+    ) -> Result<()> {
+        handle_confidential_burn(
+            ctx,
+            amount,
+            amount_opening,
+            new_decryptable_available_balance,
+        )
+    }
+
+    pub fn reconcile_confidential_supply(
+        ctx: Context<ReconcileConfidentialSupply>,
+        confidential_supply: u64,
+    ) -> Result<()> {
+        handle_reconcile_confidential_supply(ctx, confidential_supply)
     }
 }
@@ -1,13 +1,69 @@
 use anchor_lang::prelude::*;
 
+/// Number of allowlist tiers an `AllowlistPolicy` defines (`AllowlistEntry.tier` indexes into
+/// them); matches the width of the `allowed_recipient_tiers` mask.
+pub const ALLOWLIST_TIERS: usize = 8;
+
 #[account]
 #[derive(InitSpace)]
 pub struct AllowlistEntry {
     pub bump: u8,
     pub wallet: Pubkey,
     pub is_allowed: bool,
+    pub expires_at: Option<i64>, // Approval lapses at this unix time (e.g. KYC renewal); None = no expiry
+    pub tier: u8,                // Index into AllowlistPolicy.tiers; 0 for entries created before tiers
 }
 
 impl AllowlistEntry {
     pub const SEED_PREFIX: &'static [u8] = b"allowlist";
+
+    /// Whether the wallet is allowed at `now` (allowed and not yet expired).
+    pub fn is_active(&self, now: i64) -> bool {
+        self.is_allowed && !matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+
+    /// Decode an entry stored in the current or any earlier layout. Earlier layouts are
+    /// prefixes of the current one, so zero-padding them reads the missing fields as unset.
+    pub fn try_deserialize_any_layout(data: &[u8]) -> Result<Self> {
+        let mut padded = [0u8; 8 + Self::INIT_SPACE];
+        require!(
+            data.len() <= padded.len(),
+            ErrorCode::AccountDidNotDeserialize
+        );
+        padded[..data.len()].copy_from_slice(data);
+        Self::try_deserialize(&mut &padded[..])
+    }
+}
+
+/// Transfer rules per allowlist tier, at ["allowlist-policy", mint]. Enforced by the transfer
+/// hook once created; tiers start unrestricted.
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistPolicy {
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub tiers: [AllowlistTier; ALLOWLIST_TIERS],
+}
+
+impl AllowlistPolicy {
+    pub const SEED_PREFIX: &'static [u8] = b"allowlist-policy";
+}
+
+/// What a wallet in one allowlist tier may send.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct AllowlistTier {
+    pub max_transfer_amount: u64,    // Largest single transfer; 0 = no cap
+    pub allowed_recipient_tiers: u8, // Bit n set = may send to wallets in tier n
+}
+
+impl AllowlistTier {
+    pub const UNRESTRICTED: Self = Self {
+        max_transfer_amount: 0,
+        allowed_recipient_tiers: u8::MAX,
+    };
+
+    pub fn may_send_to(&self, recipient_tier: u8) -> bool {
+        (recipient_tier as usize) < ALLOWLIST_TIERS
+            && self.allowed_recipient_tiers & (1 << recipient_tier) != 0
+    }
 }
@@ -6,8 +6,38 @@ pub struct BlacklistEntry {
     pub bump: u8,
     pub account: Pubkey,
     pub is_blacklisted: bool,
+    pub reason: BlacklistReason,
+    pub case_reference: [u8; 32], // Hash of the off-chain case file / ticket id; all zero if none
+    pub blacklisted_at: i64,      // Unix time of the most recent listing
+    pub blacklisted_by: Pubkey,   // Signer of the most recent listing
+    pub expires_at: Option<i64>,  // Listing lapses at this unix time; None = until removed
 }
 
 impl BlacklistEntry {
     pub const SEED_PREFIX: &'static [u8] = b"blacklist";
+
+    /// Whether the entry blocks transfers at `now` (listed and not yet expired).
+    pub fn is_active(&self, now: i64) -> bool {
+        self.is_blacklisted && !matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+}
+
+/// BlacklistEntry layout as first deployed. Still a prefix of the current layout, so the
+/// transfer hook can read `is_blacklisted` from entries that were never migrated.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BlacklistEntryV0 {
+    pub bump: u8,
+    pub account: Pubkey,
+    pub is_blacklisted: bool,
+}
+
+/// Why an account was blacklisted. `Unspecified` (zero) is what migrated legacy entries read as.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum BlacklistReason {
+    Unspecified,
+    Sanctions,
+    Fraud,
+    CourtOrder,
+    LawEnforcement,
+    Other,
 }
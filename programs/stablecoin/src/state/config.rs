@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+/// Number of transfer limit tiers a config defines (`TransferLimit.tier` indexes into them).
+pub const TRANSFER_LIMIT_TIERS: usize = 4;
+
 #[account]
 #[derive(InitSpace)]
 pub struct StablecoinConfig {
@@ -13,14 +16,63 @@ pub struct StablecoinConfig {
     #[max_len(256)]
     pub uri: String,
     pub decimals: u8,
-    pub is_paused: bool,
+    pub pause_flags: u8, // Bitmask of PAUSE_* operations currently halted; 0 = fully operational
     pub enable_permanent_delegate: bool,
     pub enable_transfer_hook: bool,
     pub default_account_frozen: bool,
     pub enable_confidential_transfers: bool, // SSS-3 POC
     pub enable_allowlist: bool,               // SSS-3: restrict transfers to allowed wallets when enabled
+    pub pending_authority: Option<Pubkey>,    // Proposed master authority; set by propose_authority, cleared on accept/cancel
+    pub max_supply: Option<u64>,              // Global issuance cap across all minters; None = uncapped
+    pub pause_reason: PauseReason,            // Reason given by the most recent pause
+    pub paused_at: i64,                       // Unix time of the most recent pause; 0 = never paused
+    pub transfer_limit_tiers: [TransferLimitTier; TRANSFER_LIMIT_TIERS], // Caps inherited via the hook's TransferLimit
+    pub travel_rule_threshold: Option<u64>, // Transfers above this need a travel-rule memo in the transaction; None = disabled
+    pub enable_confidential_mint_burn: bool, // SSS-3: mint carries ConfidentialMintBurn; false on migrated configs
+    pub confidential_supply: u64, // Net confidential mint minus burn; counts toward max_supply alongside mint.supply
+    pub oracle_feed: Option<OracleFeed>, // Feed mint_at_oracle_price must price against; None = oracle minting disabled
 }
 
 impl StablecoinConfig {
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    pub const PAUSE_MINT: u8 = 1 << 0;
+    pub const PAUSE_BURN: u8 = 1 << 1;
+    /// Enforced by the transfer hook; seizures by the config PDA are exempt.
+    pub const PAUSE_TRANSFER: u8 = 1 << 2;
+    pub const PAUSE_SEIZE: u8 = 1 << 3;
+    pub const PAUSE_ALL: u8 =
+        Self::PAUSE_MINT | Self::PAUSE_BURN | Self::PAUSE_TRANSFER | Self::PAUSE_SEIZE;
+
+    /// Whether any operation in `flags` is currently paused.
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.pause_flags & flags != 0
+    }
+}
+
+/// Outbound transfer caps for one tier, enforced by the transfer hook for wallets with a
+/// `TransferLimit` account. Zero means no cap, which is what migrated configs read as.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct TransferLimitTier {
+    pub daily_limit: u64,        // Max amount sent within any rolling 24h window
+    pub per_transfer_limit: u64, // Max amount of a single transfer
+}
+
+/// The Switchboard queue and feed `mint_at_oracle_price` accepts quotes from. Pinned by the
+/// master authority because the quote verifier trusts whichever queue it is handed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct OracleFeed {
+    pub queue: Pubkey, // Switchboard queue whose oracle signing keys must sign the quote
+    pub feed_id: [u8; 32], // Feed hash the price is read from within the quote
+}
+
+/// Why the stablecoin was paused. `Unspecified` (zero) is what migrated configs read as.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PauseReason {
+    Unspecified,
+    Incident,
+    Exploit,
+    Maintenance,
+    Compliance,
+    Other,
 }
@@ -1,8 +1,29 @@
 use anchor_lang::prelude::*;
 
+/// Number of buckets the rolling quota window is split into (hourly for the default 24h window).
+pub const MINT_WINDOW_BUCKETS: usize = 24;
+
 #[account]
 #[derive(InitSpace)]
 pub struct MinterConfig {
+    pub bump: u8,
+    pub minter: Pubkey,
+    pub is_active: bool,
+    pub daily_minted: u64,     // Amount minted within the current rolling window (sum of buckets)
+    pub total_minted: u64,
+    pub daily_mint_quota: u64, // Max amount mintable within any rolling window
+    pub last_mint_timestamp: i64,
+    pub window_seconds: u32,
+    pub last_bucket: i64, // Absolute bucket number (unix_timestamp / bucket length) of the newest bucket
+    pub mint_buckets: [u64; MINT_WINDOW_BUCKETS],
+    pub quota_mode: MintQuotaMode,
+    pub mint_allowance: u64, // Remaining amount mintable in Allowance mode; decremented on each mint
+}
+
+/// MinterConfig layout as first deployed: `daily_minted` reset once a day had passed since
+/// `last_mint_timestamp`, with no rolling window. Only read by `migrate_minter_config`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MinterConfigV0 {
     pub bump: u8,
     pub minter: Pubkey,
     pub is_active: bool,
@@ -12,6 +33,97 @@ pub struct MinterConfig {
     pub last_mint_timestamp: i64,
 }
 
+impl MinterConfigV0 {
+    pub const LEN: usize = 8 + 1 + 32 + 1 + 8 * 4;
+}
+
+/// How `mint` limits a minter's issuance.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum MintQuotaMode {
+    /// `daily_mint_quota` over a rolling `window_seconds` window.
+    RollingWindow,
+    /// `mint_allowance`, which only the minter controller can raise or lower.
+    Allowance,
+}
+
 impl MinterConfig {
     pub const SEED_PREFIX: &'static [u8] = b"minter";
+    pub const DEFAULT_WINDOW_SECONDS: u32 = 86_400;
+
+    /// A window must split into whole-second buckets; anything else would be silently truncated.
+    // `is_multiple_of` needs Rust 1.87, newer than the SBF platform-tools rustc.
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn is_valid_window(window_seconds: u32) -> bool {
+        window_seconds > 0 && window_seconds as usize % MINT_WINDOW_BUCKETS == 0
+    }
+
+    fn bucket_seconds(&self) -> i64 {
+        (self.window_seconds as i64 / MINT_WINDOW_BUCKETS as i64).max(1)
+    }
+
+    /// Advance the ring buffer to `now`, zeroing buckets that fell out of the window,
+    /// and refresh `daily_minted` to the amount still inside the window.
+    pub fn roll_window(&mut self, now: i64) {
+        let current = now / self.bucket_seconds();
+        let elapsed = current.saturating_sub(self.last_bucket);
+        if elapsed >= MINT_WINDOW_BUCKETS as i64 {
+            self.mint_buckets = [0; MINT_WINDOW_BUCKETS];
+        } else {
+            for bucket in (self.last_bucket + 1)..=current {
+                self.mint_buckets[bucket.rem_euclid(MINT_WINDOW_BUCKETS as i64) as usize] = 0;
+            }
+        }
+        if elapsed > 0 {
+            self.last_bucket = current;
+        }
+        self.daily_minted = self.mint_buckets.iter().sum();
+    }
+
+    /// Record `amount` in the newest bucket. Call after `roll_window`.
+    pub fn record_mint(&mut self, amount: u64) -> Option<()> {
+        let slot = self.last_bucket.rem_euclid(MINT_WINDOW_BUCKETS as i64) as usize;
+        self.mint_buckets[slot] = self.mint_buckets[slot].checked_add(amount)?;
+        self.daily_minted = self.daily_minted.checked_add(amount)?;
+        Some(())
+    }
+
+    /// Change the window length. Everything still in the old window is carried into the newest
+    /// bucket so it keeps counting until a full new window has passed (never under-counts).
+    pub fn set_window(&mut self, window_seconds: u32, now: i64) {
+        self.roll_window(now);
+        let carried = self.daily_minted;
+        self.window_seconds = window_seconds;
+        self.mint_buckets = [0; MINT_WINDOW_BUCKETS];
+        self.last_bucket = now / self.bucket_seconds();
+        let slot = self.last_bucket.rem_euclid(MINT_WINDOW_BUCKETS as i64) as usize;
+        self.mint_buckets[slot] = carried;
+        self.daily_minted = carried;
+    }
+
+    /// Rewrite a [`MinterConfigV0`] as a rolling-window minter on the default window. Usage
+    /// from the legacy day that has not yet reset is carried into the newest bucket, so the
+    /// minter cannot mint a second full quota straight after migrating.
+    pub fn from_v0(legacy: MinterConfigV0, now: i64) -> Self {
+        let mut config = Self {
+            bump: legacy.bump,
+            minter: legacy.minter,
+            is_active: legacy.is_active,
+            daily_minted: 0,
+            total_minted: legacy.total_minted,
+            daily_mint_quota: legacy.daily_mint_quota,
+            last_mint_timestamp: legacy.last_mint_timestamp,
+            window_seconds: Self::DEFAULT_WINDOW_SECONDS,
+            last_bucket: 0,
+            mint_buckets: [0; MINT_WINDOW_BUCKETS],
+            quota_mode: MintQuotaMode::RollingWindow,
+            mint_allowance: 0,
+        };
+        config.last_bucket = now / config.bucket_seconds();
+        if now < legacy.last_mint_timestamp.saturating_add(Self::DEFAULT_WINDOW_SECONDS as i64) {
+            let slot = config.last_bucket.rem_euclid(MINT_WINDOW_BUCKETS as i64) as usize;
+            config.mint_buckets[slot] = legacy.daily_minted;
+            config.daily_minted = legacy.daily_minted;
+        }
+        config
+    }
 }
@@ -8,8 +8,59 @@ pub struct RoleAccount {
     pub pauser: Pubkey,
     pub blacklister: Pubkey,
     pub seizer: Pubkey,
+    pub minter_controller: Pubkey, // Tops up / reduces minter allowances
+    pub allowlister: Pubkey,       // Adds / removes allowlist entries (SSS-3)
+    pub pending_burner: Option<Pubkey>,
+    pub pending_pauser: Option<Pubkey>,
+    pub pending_blacklister: Option<Pubkey>,
+    pub pending_seizer: Option<Pubkey>,
+    pub pending_minter_controller: Option<Pubkey>,
+    pub pending_allowlister: Option<Pubkey>,
+}
+
+/// RoleAccount layout as first deployed (before pending proposals and the minter
+/// controller / allowlister roles). Only read by `migrate_accounts`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RoleAccountV0 {
+    pub bump: u8,
+    pub burner: Pubkey,
+    pub pauser: Pubkey,
+    pub blacklister: Pubkey,
+    pub seizer: Pubkey,
+}
+
+impl RoleAccountV0 {
+    pub const LEN: usize = 8 + 1 + 32 * 4;
 }
 
 impl RoleAccount {
     pub const SEED_PREFIX: &'static [u8] = b"roles";
+
+    /// Current holder and pending proposal for an operational role. Master is stored in config.
+    pub fn slot_mut(&mut self, role: AuthorityRole) -> Option<(&mut Pubkey, &mut Option<Pubkey>)> {
+        match role {
+            AuthorityRole::Master => None,
+            AuthorityRole::Burner => Some((&mut self.burner, &mut self.pending_burner)),
+            AuthorityRole::Pauser => Some((&mut self.pauser, &mut self.pending_pauser)),
+            AuthorityRole::Blacklister => Some((&mut self.blacklister, &mut self.pending_blacklister)),
+            AuthorityRole::Seizer => Some((&mut self.seizer, &mut self.pending_seizer)),
+            AuthorityRole::MinterController => Some((
+                &mut self.minter_controller,
+                &mut self.pending_minter_controller,
+            )),
+            AuthorityRole::Allowlister => Some((&mut self.allowlister, &mut self.pending_allowlister)),
+        }
+    }
+}
+
+/// Authority slot targeted by the two-step propose/accept/cancel flow.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum AuthorityRole {
+    Master,
+    Burner,
+    Pauser,
+    Blacklister,
+    Seizer,
+    MinterController,
+    Allowlister,
 }
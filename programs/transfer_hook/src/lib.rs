@@ -4,14 +4,20 @@
 #![allow(unexpected_cfgs)]
 
 use anchor_lang::prelude::*;
-#[allow(deprecated)] // Prefer solana_system_interface when Anchor exposes it; create_account works.
-use anchor_lang::solana_program::system_instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked,
+};
 use anchor_spl::token_interface::Mint;
+use spl_token_2022::extension::transfer_hook::{TransferHook, TransferHookAccount};
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::{Account as TokenAccount, Mint as TokenMint};
 use spl_tlv_account_resolution::account::ExtraAccountMeta;
 use spl_tlv_account_resolution::state::ExtraAccountMetaList;
 use spl_tlv_account_resolution::seeds::Seed;
 use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 use spl_transfer_hook_interface::instruction::TransferHookInstruction;
+use spl_type_length_value::state::TlvStateBorrowed;
+use stablecoin::state::{StablecoinConfig, TransferLimitTier};
 
 declare_id!("4VKhzS8cyVXJPD9VpAopu4g16wzKA6YDm8Wr2TadR7qi");
 
@@ -19,108 +25,107 @@ declare_id!("4VKhzS8cyVXJPD9VpAopu4g16wzKA6YDm8Wr2TadR7qi");
 pub mod transfer_hook {
     use super::*;
 
+    /// Create the validation list for a mint, laid out from the stablecoin config like
+    /// `update_extra_account_meta_list`. Signed by the stablecoin's master authority (or the
+    /// config PDA via CPI); the account is created with `init`, so an existing list can only be
+    /// changed through `update_extra_account_meta_list`.
     pub fn initialize_extra_account_meta_list(
         ctx: Context<InitializeExtraAccountMetaList>,
-        enable_allowlist: bool,
     ) -> Result<()> {
-        let mint = ctx.accounts.mint.key();
-
-        // Extra accounts required for transfer validation.
-        // Base (SSS-2): 5 stablecoin_program, 6 source_blacklist, 7 dest_blacklist.
-        // With allowlist (SSS-3): + 6 config, 7 source_blacklist, 8 dest_blacklist, 9 source_allowlist, 10 dest_allowlist.
-        let mut account_metas = vec![
-            ExtraAccountMeta::new_with_pubkey(&stablecoin::ID, false, false)?,
-            // Source Blacklist PDA: ["blacklist", mint, owner(source)]
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 1 },
-                    Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
-                ],
-                false,
-                false,
-            )?,
-            // Destination Blacklist PDA: ["blacklist", mint, owner(dest)]
-            ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"blacklist".to_vec() },
-                    Seed::AccountKey { index: 1 },
-                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 },
-                ],
-                false,
-                false,
-            )?,
-        ];
-
-        if enable_allowlist {
-            // Config PDA: ["config", mint] — read enable_allowlist in execute (index 6)
-            account_metas.push(ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"config".to_vec() },
-                    Seed::AccountKey { index: 1 },
-                ],
-                false,
-                false,
-            )?);
-            // Source allowlist PDA (index 7), dest allowlist PDA (index 8)
-            account_metas.push(ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"allowlist".to_vec() },
-                    Seed::AccountKey { index: 1 },
-                    Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
-                ],
-                false,
-                false,
-            )?);
-            account_metas.push(ExtraAccountMeta::new_external_pda_with_seeds(
-                5,
-                &[
-                    Seed::Literal { bytes: b"allowlist".to_vec() },
-                    Seed::AccountKey { index: 1 },
-                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 },
-                ],
-                false,
-                false,
-            )?);
-        }
+        let account_metas = extra_account_metas(ctx.accounts.config.enable_allowlist)?;
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &account_metas)?;
 
-        let rent = Rent::get()?;
+        msg!("InitializeExtraAccountMetaList Complete");
+        Ok(())
+    }
+
+    /// Rewrite the validation list to match the stablecoin config (e.g. after
+    /// `set_allowlist_enabled`), resizing the account and settling rent with the authority.
+    /// Only the config's master authority may call this.
+    pub fn update_extra_account_meta_list(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+        let account_metas = extra_account_metas(ctx.accounts.config.enable_allowlist)?;
         let space = ExtraAccountMetaList::size_of(account_metas.len())?;
-        let lamports = rent.minimum_balance(space);
-
-        if ctx.accounts.extra_account_meta_list.data_is_empty() {
-             anchor_lang::solana_program::program::invoke_signed(
-                &system_instruction::create_account(
-                    ctx.accounts.payer.key,
-                    ctx.accounts.extra_account_meta_list.key,
-                    lamports,
-                    space as u64,
-                    ctx.program_id,
-                ),
-                &[
-                    ctx.accounts.payer.to_account_info(),
-                    ctx.accounts.extra_account_meta_list.to_account_info(),
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        let list = ctx.accounts.extra_account_meta_list.to_account_info();
+        let authority = ctx.accounts.authority.to_account_info();
+        let current = list.lamports();
+        if current < lamports {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
                     ctx.accounts.system_program.to_account_info(),
-                ],
-                &[&[b"extra-account-metas", mint.as_ref(), &[ctx.bumps.extra_account_meta_list]]],
+                    anchor_lang::system_program::Transfer {
+                        from: authority.clone(),
+                        to: list.clone(),
+                    },
+                ),
+                lamports - current,
             )?;
+        } else if current > lamports {
+            // The list is owned by this program, so excess rent can be moved out directly.
+            **list.try_borrow_mut_lamports()? = lamports;
+            **authority.try_borrow_mut_lamports()? += current - lamports;
         }
+        list.realloc(space, false)?;
 
-        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
-        for i in 0..data.len() {
-            data[i] = 0;
-        }
+        let mut data = list.try_borrow_mut_data()?;
+        data.fill(0);
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &account_metas)?;
 
-        ExtraAccountMetaList::init::<ExecuteInstruction>(
-            &mut data,
-            &account_metas,
-        )?;
+        msg!(
+            "UpdateExtraAccountMetaList Complete (allowlist: {})",
+            ctx.accounts.config.enable_allowlist
+        );
+        Ok(())
+    }
 
-        msg!("InitializeExtraAccountMetaList Complete");
+    /// Give `wallet` outbound caps for this mint. `tier` picks the config's
+    /// `transfer_limit_tiers` entry; `daily_limit` / `per_transfer_limit` override it when set.
+    /// Only the config's master authority may call this.
+    pub fn initialize_transfer_limit(
+        ctx: Context<InitializeTransferLimit>,
+        tier: u8,
+        daily_limit: Option<u64>,
+        per_transfer_limit: Option<u64>,
+    ) -> Result<()> {
+        require!(
+            (tier as usize) < stablecoin::state::TRANSFER_LIMIT_TIERS,
+            TransferHookError::InvalidTransferLimitTier
+        );
+        let limit = &mut ctx.accounts.transfer_limit;
+        limit.bump = ctx.bumps.transfer_limit;
+        limit.mint = ctx.accounts.mint.key();
+        limit.owner = ctx.accounts.wallet.key();
+        limit.tier = tier;
+        limit.daily_limit = daily_limit;
+        limit.per_transfer_limit = per_transfer_limit;
+        limit.last_bucket = Clock::get()?.unix_timestamp / TransferLimit::BUCKET_SECONDS;
+        limit.buckets = [0; TRANSFER_WINDOW_BUCKETS];
+        Ok(())
+    }
+
+    /// Change a wallet's tier or overrides. Amounts already sent in the window keep counting.
+    pub fn update_transfer_limit(
+        ctx: Context<UpdateTransferLimit>,
+        tier: u8,
+        daily_limit: Option<u64>,
+        per_transfer_limit: Option<u64>,
+    ) -> Result<()> {
+        require!(
+            (tier as usize) < stablecoin::state::TRANSFER_LIMIT_TIERS,
+            TransferHookError::InvalidTransferLimitTier
+        );
+        let limit = &mut ctx.accounts.transfer_limit;
+        limit.tier = tier;
+        limit.daily_limit = daily_limit;
+        limit.per_transfer_limit = per_transfer_limit;
+        Ok(())
+    }
+
+    /// Remove a wallet's caps and refund the PDA's rent to `authority`. Limits are opt-in:
+    /// once closed the wallet is uncapped again, like one that never had a `TransferLimit`.
+    pub fn close_transfer_limit(_ctx: Context<CloseTransferLimit>) -> Result<()> {
         Ok(())
     }
 
@@ -129,12 +134,11 @@ pub mod transfer_hook {
         accounts: &'info [AccountInfo<'info>],
         ix_data: &[u8],
     ) -> Result<()> {
-        let mut ix_data_ptr: &[u8] = ix_data;
-        if let Ok(instruction) = TransferHookInstruction::unpack(&mut ix_data_ptr) {
+        if let Ok(instruction) = TransferHookInstruction::unpack(ix_data) {
             return match instruction {
                 TransferHookInstruction::Execute { amount } => {
                     msg!("Transfer Hook - Execute called with amount: {}", amount);
-                    handle_execute(accounts, amount)
+                    handle_execute(accounts, ix_data, amount)
                 }
                 _ => Err(ProgramError::InvalidInstructionData.into()),
             };
@@ -143,80 +147,605 @@ pub mod transfer_hook {
     }
 }
 
-pub fn handle_execute(accounts: &[AccountInfo], _amount: u64) -> Result<()> {
-    if accounts.len() < 8 {
+/// Extra accounts required for transfer validation, in the order `handle_execute` reads them.
+fn extra_account_metas(enable_allowlist: bool) -> Result<Vec<ExtraAccountMeta>> {
+    // Base (SSS-2): 5 stablecoin_program, 6 source_blacklist, 7 dest_blacklist, 8 config.
+    // With allowlist (SSS-3): + 9 source_allowlist, 10 dest_allowlist.
+    // Then authority_blacklist, source_transfer_limit, (with allowlist) allowlist_policy and the
+    // instructions sysvar. `handle_execute` locates everything after 7 by matching the stored metas, so later
+    // additions only ever go at the end.
+    let mut account_metas = vec![
+        ExtraAccountMeta::new_with_pubkey(&stablecoin::ID, false, false)?,
+        owner_pda_meta(b"blacklist", 0)?,
+        owner_pda_meta(b"blacklist", 2)?,
+        config_meta()?,
+    ];
+    if enable_allowlist {
+        account_metas.push(owner_pda_meta(b"allowlist", 0)?);
+        account_metas.push(owner_pda_meta(b"allowlist", 2)?);
+    }
+    account_metas.push(authority_blacklist_meta()?);
+    account_metas.push(transfer_limit_meta()?);
+    if enable_allowlist {
+        account_metas.push(allowlist_policy_meta()?);
+    }
+    account_metas.push(instructions_sysvar_meta()?);
+
+    Ok(account_metas)
+}
+
+/// Stablecoin PDA `[prefix, mint, owner]`, where owner is read from token account
+/// `account_index` (0 = source, 2 = destination).
+fn owner_pda_meta(prefix: &[u8], account_index: u8) -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_external_pda_with_seeds(
+        5,
+        &[
+            Seed::Literal { bytes: prefix.to_vec() },
+            Seed::AccountKey { index: 1 },
+            Seed::AccountData { account_index, data_index: 32, length: 32 },
+        ],
+        false,
+        false,
+    )?)
+}
+
+/// Config PDA: ["config", mint] — pause state, allowlist flag and transfer limit tiers.
+fn config_meta() -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_external_pda_with_seeds(
+        5,
+        &[
+            Seed::Literal { bytes: b"config".to_vec() },
+            Seed::AccountKey { index: 1 },
+        ],
+        false,
+        false,
+    )?)
+}
+
+/// Authority (owner or delegate) blacklist PDA: ["blacklist", mint, authority].
+fn authority_blacklist_meta() -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_external_pda_with_seeds(
+        5,
+        &[
+            Seed::Literal { bytes: b"blacklist".to_vec() },
+            Seed::AccountKey { index: 1 },
+            Seed::AccountKey { index: 3 },
+        ],
+        false,
+        false,
+    )?)
+}
+
+/// Allowlist policy PDA: ["allowlist-policy", mint] — per-tier amount caps and recipient tiers.
+fn allowlist_policy_meta() -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_external_pda_with_seeds(
+        5,
+        &[
+            Seed::Literal { bytes: b"allowlist-policy".to_vec() },
+            Seed::AccountKey { index: 1 },
+        ],
+        false,
+        false,
+    )?)
+}
+
+/// Instructions sysvar, scanned for a travel-rule memo on transfers above the config threshold.
+fn instructions_sysvar_meta() -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_with_pubkey(&instructions_sysvar::ID, false, false)?)
+}
+
+/// This program's TransferLimit PDA for the source owner; writable so `execute` can record usage.
+fn transfer_limit_meta() -> Result<ExtraAccountMeta> {
+    Ok(ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal { bytes: TransferLimit::SEED_PREFIX.to_vec() },
+            Seed::AccountKey { index: 1 },
+            Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
+        ],
+        false,
+        true,
+    )?)
+}
+
+// Execute account positions: the four Token-2022 transfer accounts, the validation list, then
+// the extra accounts in the order `extra_account_metas` declares them.
+const SOURCE_INDEX: usize = 0;
+const MINT_INDEX: usize = 1;
+const DEST_INDEX: usize = 2;
+const AUTHORITY_INDEX: usize = 3;
+const VALIDATION_INDEX: usize = 4;
+const EXTRA_ACCOUNTS_START: usize = 5;
+const SOURCE_BLACKLIST_INDEX: usize = 6;
+const DEST_BLACKLIST_INDEX: usize = 7;
+const CONFIG_INDEX: usize = 8;
+const SOURCE_ALLOWLIST_INDEX: usize = 9;
+const DEST_ALLOWLIST_INDEX: usize = 10;
+
+/// Positions of the optional extra accounts in a mint's stored list. Lists built by older
+/// versions lack some of them, so they are located by matching the stored metas rather than
+/// assumed from the list length.
+struct ExtraLayout {
+    config: bool,
+    allowlist: bool,
+    authority_blacklist: Option<usize>,
+    transfer_limit: Option<usize>,
+    allowlist_policy: Option<usize>,
+    instructions_sysvar: Option<usize>,
+}
+
+impl ExtraLayout {
+    fn from_list(data: &[u8]) -> Result<Self> {
+        let state = TlvStateBorrowed::unpack(data)?;
+        let list = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)?;
+        let metas = list.data();
+        let position = |meta: ExtraAccountMeta| {
+            metas
+                .iter()
+                .position(|stored| *stored == meta)
+                .map(|i| EXTRA_ACCOUNTS_START + i)
+        };
+        let config = position(config_meta()?);
+        let allowlist = (
+            position(owner_pda_meta(b"allowlist", 0)?),
+            position(owner_pda_meta(b"allowlist", 2)?),
+        );
+        // Config and allowlists have always been at their fixed positions when present.
+        require!(
+            matches!(config, None | Some(CONFIG_INDEX))
+                && matches!(
+                    allowlist,
+                    (None, None) | (Some(SOURCE_ALLOWLIST_INDEX), Some(DEST_ALLOWLIST_INDEX))
+                ),
+            TransferHookError::InvalidExtraAccounts
+        );
+        Ok(Self {
+            config: config.is_some(),
+            allowlist: allowlist.0.is_some(),
+            authority_blacklist: position(authority_blacklist_meta()?),
+            transfer_limit: position(transfer_limit_meta()?),
+            allowlist_policy: position(allowlist_policy_meta()?),
+            instructions_sysvar: position(instructions_sysvar_meta()?),
+        })
+    }
+}
+
+pub fn handle_execute(accounts: &[AccountInfo], ix_data: &[u8], amount: u64) -> Result<()> {
+    if accounts.len() < DEST_BLACKLIST_INDEX + 1 {
         return Err(ProgramError::NotEnoughAccountKeys.into());
     }
 
-    let mint_info = &accounts[1];
-    let authority_info = &accounts[3];
+    let mint_info = &accounts[MINT_INDEX];
+    let authority_info = &accounts[AUTHORITY_INDEX];
 
-    // Admin bypass (Seize)
+    // Admin bypass (Seize). Runs before list validation: in a seize the config PDA signs, so the
+    // signer flags would not match the stored (non-signer) metas.
     let (config_pda, _bump) = Pubkey::find_program_address(
         &[b"config", mint_info.key().as_ref()],
         &stablecoin::ID,
     );
 
     if authority_info.key() == config_pda {
+        assert_token_2022_transfer(&accounts[SOURCE_INDEX], mint_info)?;
         msg!("Execute - Admin bypass (Seize)");
         return Ok(());
     }
 
-    // Blacklist: indices 6 and 7. Deserialize BlacklistEntry from stablecoin state (no hardcoded offsets).
-    let (source_blacklist_ix, dest_blacklist_ix) = (6, 7);
-    for (label, acc) in [
-        ("source", &accounts[source_blacklist_ix]),
-        ("destination", &accounts[dest_blacklist_ix]),
+    // Resolve the extra accounts from the mint's stored list rather than trusting the caller:
+    // every provided key must equal the PDA the list derives for this transfer.
+    let validation_info = &accounts[VALIDATION_INDEX];
+    let (validation_pda, _bump) =
+        Pubkey::find_program_address(&[b"extra-account-metas", mint_info.key().as_ref()], &crate::ID);
+    if validation_info.key() != validation_pda || *validation_info.owner != crate::ID {
+        msg!("Execute - Invalid validation account: {}", validation_info.key());
+        return Err(TransferHookError::InvalidExtraAccounts.into());
+    }
+    // The list is sized exactly by init/update, so its length pins the number of extra accounts.
+    let extra_count = accounts.len() - EXTRA_ACCOUNTS_START;
+    if validation_info.data_len() != ExtraAccountMetaList::size_of(extra_count)? {
+        msg!("Execute - Expected extra accounts do not match the validation list");
+        return Err(TransferHookError::InvalidExtraAccounts.into());
+    }
+    let layout = ExtraLayout::from_list(&validation_info.try_borrow_data()?)?;
+
+    // Every compliance account the hook reads must be the stablecoin PDA for this mint and the
+    // transfer's wallets, and (when it exists) owned by the stablecoin program.
+    let mint_key = mint_info.key();
+    let source_owner = token_account_owner(&accounts[SOURCE_INDEX])?;
+    let dest_owner = token_account_owner(&accounts[DEST_INDEX])?;
+    for (acc, owner) in [
+        (&accounts[SOURCE_BLACKLIST_INDEX], &source_owner),
+        (&accounts[DEST_BLACKLIST_INDEX], &dest_owner),
     ] {
+        verify_stablecoin_pda(
+            acc,
+            &[stablecoin::state::BlacklistEntry::SEED_PREFIX, mint_key.as_ref(), owner.as_ref()],
+            TransferHookError::InvalidBlacklistAccount,
+        )?;
+    }
+    if let Some(authority_blacklist_ix) = layout.authority_blacklist {
+        verify_stablecoin_pda(
+            &accounts[authority_blacklist_ix],
+            &[
+                stablecoin::state::BlacklistEntry::SEED_PREFIX,
+                mint_key.as_ref(),
+                authority_info.key.as_ref(),
+            ],
+            TransferHookError::InvalidBlacklistAccount,
+        )?;
+    }
+    if layout.config {
+        let config_info = &accounts[CONFIG_INDEX];
+        verify_stablecoin_owner(config_info)?;
+        if config_info.key() != config_pda {
+            msg!("Execute - Unexpected config account: {}", config_info.key());
+            return Err(TransferHookError::InvalidConfigAccount.into());
+        }
+    }
+    if let Some(transfer_limit_ix) = layout.transfer_limit {
+        let limit_info = &accounts[transfer_limit_ix];
+        let (expected, _bump) = Pubkey::find_program_address(
+            &[TransferLimit::SEED_PREFIX, mint_key.as_ref(), source_owner.as_ref()],
+            &crate::ID,
+        );
+        if limit_info.key() != expected
+            || (!limit_info.data_is_empty() && *limit_info.owner != crate::ID)
+        {
+            msg!("Execute - Unexpected transfer limit account: {}", limit_info.key());
+            return Err(TransferHookError::InvalidTransferLimitAccount.into());
+        }
+    }
+    if layout.allowlist {
+        for (acc, owner) in [
+            (&accounts[SOURCE_ALLOWLIST_INDEX], &source_owner),
+            (&accounts[DEST_ALLOWLIST_INDEX], &dest_owner),
+        ] {
+            verify_stablecoin_pda(
+                acc,
+                &[stablecoin::state::AllowlistEntry::SEED_PREFIX, mint_key.as_ref(), owner.as_ref()],
+                TransferHookError::InvalidAllowlistAccount,
+            )?;
+        }
+    }
+    if let Some(allowlist_policy_ix) = layout.allowlist_policy {
+        verify_stablecoin_pda(
+            &accounts[allowlist_policy_ix],
+            &[stablecoin::state::AllowlistPolicy::SEED_PREFIX, mint_key.as_ref()],
+            TransferHookError::InvalidAllowlistPolicyAccount,
+        )?;
+    }
+
+    // Remaining positions (stablecoin program, signer/writable flags) against the stored list.
+    ExtraAccountMetaList::check_account_infos::<ExecuteInstruction>(
+        accounts,
+        ix_data,
+        &crate::ID,
+        &validation_info.try_borrow_data()?,
+    )
+    .map_err(|_| {
+        msg!("Execute - Extra account does not match the validation list");
+        TransferHookError::InvalidExtraAccounts
+    })?;
+
+    assert_token_2022_transfer(&accounts[SOURCE_INDEX], mint_info)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Lists created before the config PDA was added (legacy SSS-2) hold only the stablecoin
+    // program and the two blacklist PDAs, so there is no pause or allowlist check.
+    let config = if layout.config {
+        let config_data = accounts[CONFIG_INDEX].try_borrow_data()?;
+        let mut config_data_slice: &[u8] = config_data.as_ref();
+        Some(
+            StablecoinConfig::try_deserialize(&mut config_data_slice)
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+        )
+    } else {
+        None
+    };
+
+    if let Some(config) = &config {
+        if config.is_paused(StablecoinConfig::PAUSE_TRANSFER) {
+            msg!("Execute - Transfers are paused");
+            return Err(TransferHookError::TransfersPaused.into());
+        }
+    }
+
+    // Blacklist PDAs. Deserialize BlacklistEntry from stablecoin state (no hardcoded offsets).
+    // An entry past its expiry is treated as cleared. The authority check stops a blacklisted
+    // delegate from moving someone else's tokens; lists built before it skip it until rebuilt.
+    let authority_blacklist = layout
+        .authority_blacklist
+        .map(|ix| ("authority", &accounts[ix]));
+    for (label, acc) in [
+        ("source", &accounts[SOURCE_BLACKLIST_INDEX]),
+        ("destination", &accounts[DEST_BLACKLIST_INDEX]),
+    ]
+    .into_iter()
+    .chain(authority_blacklist)
+    {
         if !acc.data_is_empty() {
             let data = acc.try_borrow_data()?;
             let mut slice: &[u8] = data.as_ref();
-            if let Ok(entry) = stablecoin::state::BlacklistEntry::try_deserialize(&mut slice) {
-                if entry.is_blacklisted {
-                    msg!("Execute - Blocked {} account: {}", label, acc.key());
-                    return Err(TransferHookError::Blacklisted.into());
+            let is_blacklisted = match stablecoin::state::BlacklistEntry::try_deserialize(&mut slice) {
+                Ok(entry) => entry.is_active(now),
+                // Entries created before reason/case fields existed (not yet migrated) are
+                // shorter; the original fields are still a prefix of the current layout.
+                Err(_) if data.starts_with(stablecoin::state::BlacklistEntry::DISCRIMINATOR) => {
+                    stablecoin::state::BlacklistEntryV0::deserialize(&mut &data[8..])
+                        .map(|entry| entry.is_blacklisted)
+                        .unwrap_or(false)
                 }
+                Err(_) => false,
+            };
+            if is_blacklisted {
+                msg!("Execute - Blocked {} account: {}", label, acc.key());
+                return Err(TransferHookError::Blacklisted.into());
             }
         }
     }
 
-    // SSS-3: when allowlist is enabled, require source and dest to be on allowlist.
-    // Token-2022 CPI account layouts observed:
-    // - len 11: [source, mint, dest, authority, extra_meta_list, stablecoin, s_bl, d_bl, config, s_al, d_al]
-    // - len 10: [source, mint, dest, authority, stablecoin, s_bl, d_bl, config, s_al, d_al]
-    if accounts.len() >= 10 {
-        let (config_ix, source_al_ix, dest_al_ix) = if accounts.len() >= 11 {
-            (8, 9, 10)
-        } else {
-            (7, 8, 9)
-        };
-        let config_acc = &accounts[config_ix];
-        let source_allowlist_acc = &accounts[source_al_ix];
-        let dest_allowlist_acc = &accounts[dest_al_ix];
-        if !config_acc.data_is_empty() {
-            let config_data = config_acc.try_borrow_data()?;
-            let mut config_data_slice: &[u8] = config_data.as_ref();
-            let config = stablecoin::state::StablecoinConfig::try_deserialize(&mut config_data_slice)
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-            if config.enable_allowlist {
-                for (label, acc) in [("source", source_allowlist_acc), ("destination", dest_allowlist_acc)] {
-                    if acc.data_is_empty() {
-                        msg!("Execute - Allowlist required but {} not on allowlist", label);
-                        return Err(TransferHookError::NotOnAllowlist.into());
-                    }
-                    let data = acc.try_borrow_data()?;
-                    let mut slice: &[u8] = data.as_ref();
-                    let entry = stablecoin::state::AllowlistEntry::try_deserialize(&mut slice)
+    // SSS-3: when allowlist is enabled, require source and dest to be on allowlist. A list built
+    // without the allowlist PDAs while the flag is on fails closed until it is rebuilt. Once the
+    // mint has an allowlist policy, the source's tier also caps the amount and the recipient tier.
+    if let Some(config) = &config {
+        if config.enable_allowlist {
+            if !layout.allowlist {
+                msg!("Execute - Allowlist enabled but the validation list has no allowlist accounts");
+                return Err(TransferHookError::NotOnAllowlist.into());
+            }
+            let mut tiers = [0u8; 2];
+            for (tier, (label, acc)) in tiers.iter_mut().zip([
+                ("source", &accounts[SOURCE_ALLOWLIST_INDEX]),
+                ("destination", &accounts[DEST_ALLOWLIST_INDEX]),
+            ]) {
+                if acc.data_is_empty() {
+                    msg!("Execute - Allowlist required but {} not on allowlist", label);
+                    return Err(TransferHookError::NotOnAllowlist.into());
+                }
+                // Entries created before expiry or tiers existed (not yet migrated) are shorter;
+                // the missing fields read as no expiry and tier 0.
+                let entry =
+                    stablecoin::state::AllowlistEntry::try_deserialize_any_layout(&acc.try_borrow_data()?)
                         .map_err(|_| ProgramError::InvalidAccountData)?;
-                    if !entry.is_allowed {
-                        msg!("Execute - {} wallet not allowed", label);
-                        return Err(TransferHookError::NotOnAllowlist.into());
-                    }
+                if !entry.is_active(now) {
+                    msg!("Execute - {} wallet not allowed", label);
+                    return Err(TransferHookError::NotOnAllowlist.into());
+                }
+                *tier = entry.tier;
+            }
+
+            // A list built before the policy meta existed cannot see the tier rules, so it fails
+            // closed rather than letting every allowlisted wallet send uncapped.
+            let Some(allowlist_policy_ix) = layout.allowlist_policy else {
+                msg!(
+                    "Execute - Validation list has no allowlist policy account; \
+                     call update_extra_account_meta_list"
+                );
+                return Err(TransferHookError::AllowlistPolicyMetaMissing.into());
+            };
+            let policy_info = &accounts[allowlist_policy_ix];
+            if !policy_info.data_is_empty() {
+                let policy = stablecoin::state::AllowlistPolicy::try_deserialize(
+                    &mut &policy_info.try_borrow_data()?[..],
+                )?;
+                let [source_tier, dest_tier] = tiers;
+                let rules = policy
+                    .tiers
+                    .get(source_tier as usize)
+                    .ok_or(TransferHookError::CounterpartyTierNotAllowed)?;
+                if rules.max_transfer_amount != 0 && amount > rules.max_transfer_amount {
+                    msg!(
+                        "Execute - {} exceeds tier {} cap of {}",
+                        amount,
+                        source_tier,
+                        rules.max_transfer_amount
+                    );
+                    return Err(TransferHookError::AllowlistTierAmountExceeded.into());
+                }
+                if !rules.may_send_to(dest_tier) {
+                    msg!("Execute - Tier {} may not send to tier {}", source_tier, dest_tier);
+                    return Err(TransferHookError::CounterpartyTierNotAllowed.into());
                 }
             }
         }
     }
 
+    // Travel rule: a transfer above the threshold needs a memo carrying the payload hash directly
+    // before it. A list built without the instructions sysvar fails closed until rebuilt.
+    if let Some(threshold) = config.as_ref().and_then(|config| config.travel_rule_threshold) {
+        if amount > threshold {
+            let has_memo = match layout.instructions_sysvar {
+                Some(ix) => has_travel_rule_memo(&accounts[ix]),
+                None => false,
+            };
+            if !has_memo {
+                msg!(
+                    "Execute - Transfer of {} above travel-rule threshold {} has no memo",
+                    amount,
+                    threshold
+                );
+                return Err(TransferHookError::TravelRuleMemoMissing.into());
+            }
+        }
+    }
+
+    // Outbound caps for source wallets that have a TransferLimit account; others are uncapped
+    // by design (limits are opt-in per wallet, see close_transfer_limit).
+    if let (Some(config), Some(transfer_limit_ix)) = (&config, layout.transfer_limit) {
+        let limit_info = &accounts[transfer_limit_ix];
+        if !limit_info.data_is_empty() {
+            let mut limit = TransferLimit::try_deserialize(&mut &limit_info.try_borrow_data()?[..])?;
+            let tier = config
+                .transfer_limit_tiers
+                .get(limit.tier as usize)
+                .ok_or(TransferHookError::InvalidTransferLimitTier)?;
+            limit.record_transfer(amount, now, tier)?;
+            limit.try_serialize(&mut &mut limit_info.try_borrow_mut_data()?[..])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// SPL Memo program (v2 and v1), whose instructions carry travel-rule attestations.
+pub const MEMO_PROGRAM_IDS: [Pubkey; 2] = [
+    pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+    pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo"),
+];
+
+/// A travel-rule memo is this prefix followed by the hex SHA-256 of the originator/beneficiary
+/// payload, e.g. `travel-rule:9f86d0...`. The payload itself stays off-chain.
+pub const TRAVEL_RULE_MEMO_PREFIX: &[u8] = b"travel-rule:";
+
+/// Whether the top-level instruction right before the one being executed is a Memo carrying a
+/// travel-rule hash. Binding the memo to its position means each transfer needs its own memo;
+/// one memo anywhere in the transaction could otherwise cover any number of transfers.
+fn has_travel_rule_memo(instructions: &AccountInfo) -> bool {
+    let Ok(current) = load_current_index_checked(instructions) else {
+        return false;
+    };
+    let Some(previous) = current.checked_sub(1) else {
+        return false;
+    };
+    match load_instruction_at_checked(previous as usize, instructions) {
+        Ok(ix) => MEMO_PROGRAM_IDS.contains(&ix.program_id) && is_travel_rule_memo(&ix.data),
+        Err(_) => false,
+    }
+}
+
+fn is_travel_rule_memo(data: &[u8]) -> bool {
+    match data.strip_prefix(TRAVEL_RULE_MEMO_PREFIX) {
+        Some(hash) => hash.len() == 64 && hash.iter().all(u8::is_ascii_hexdigit),
+        None => false,
+    }
+}
+
+/// Number of buckets in a wallet's rolling 24h transfer window (hourly).
+pub const TRANSFER_WINDOW_BUCKETS: usize = 24;
+
+/// Outbound caps for one wallet of a mint: ["transfer-limit", mint, owner]. Owned by this program
+/// so `execute` can record what the wallet sends.
+#[account]
+#[derive(InitSpace)]
+pub struct TransferLimit {
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub tier: u8,                        // Index into the config's transfer_limit_tiers
+    pub daily_limit: Option<u64>,        // Overrides the tier's daily cap when set; 0 = no cap
+    pub per_transfer_limit: Option<u64>, // Overrides the tier's per-transfer cap when set; 0 = no cap
+    pub last_bucket: i64, // Absolute bucket number (unix_timestamp / BUCKET_SECONDS) of the newest bucket
+    pub buckets: [u64; TRANSFER_WINDOW_BUCKETS], // Amount sent per bucket over the last 24h
+}
+
+impl TransferLimit {
+    pub const SEED_PREFIX: &'static [u8] = b"transfer-limit";
+    pub const BUCKET_SECONDS: i64 = 86_400 / TRANSFER_WINDOW_BUCKETS as i64;
+
+    /// Advance the ring buffer to `now`, zeroing buckets that fell out of the window.
+    fn roll_window(&mut self, now: i64) {
+        let current = now / Self::BUCKET_SECONDS;
+        let elapsed = current.saturating_sub(self.last_bucket);
+        if elapsed >= TRANSFER_WINDOW_BUCKETS as i64 {
+            self.buckets = [0; TRANSFER_WINDOW_BUCKETS];
+        } else {
+            for bucket in (self.last_bucket + 1)..=current {
+                self.buckets[bucket.rem_euclid(TRANSFER_WINDOW_BUCKETS as i64) as usize] = 0;
+            }
+        }
+        if elapsed > 0 {
+            self.last_bucket = current;
+        }
+    }
+
+    /// Check `amount` against the wallet's caps (its overrides, else `tier`) and record it.
+    fn record_transfer(&mut self, amount: u64, now: i64, tier: &TransferLimitTier) -> Result<()> {
+        let per_transfer_limit = self.per_transfer_limit.unwrap_or(tier.per_transfer_limit);
+        let daily_limit = self.daily_limit.unwrap_or(tier.daily_limit);
+        if per_transfer_limit != 0 && amount > per_transfer_limit {
+            msg!("Execute - {} exceeds per-transfer limit {}", amount, per_transfer_limit);
+            return Err(TransferHookError::PerTransferLimitExceeded.into());
+        }
+
+        self.roll_window(now);
+        let sent = self
+            .buckets
+            .iter()
+            .try_fold(amount, |total, bucket| total.checked_add(*bucket))
+            .ok_or(TransferHookError::DailyTransferLimitExceeded)?;
+        if daily_limit != 0 && sent > daily_limit {
+            msg!("Execute - {} sent in window exceeds daily limit {}", sent, daily_limit);
+            return Err(TransferHookError::DailyTransferLimitExceeded.into());
+        }
+        let slot = self.last_bucket.rem_euclid(TRANSFER_WINDOW_BUCKETS as i64) as usize;
+        self.buckets[slot] += amount;
+        Ok(())
+    }
+}
+
+/// Prove the hook is running inside a Token-2022 transfer of `mint`: Token-2022 sets the source
+/// account's `TransferHookAccount.transferring` flag only for the duration of the transfer, and
+/// only invokes the program named in the mint's TransferHook extension.
+fn assert_token_2022_transfer(source_info: &AccountInfo, mint_info: &AccountInfo) -> Result<()> {
+    if *mint_info.owner != spl_token_2022::ID {
+        msg!("Execute - Mint is not a Token-2022 mint");
+        return Err(TransferHookError::InvalidMint.into());
+    }
+    {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<TokenMint>::unpack(&mint_data)?;
+        let hook_program = mint
+            .get_extension::<TransferHook>()
+            .ok()
+            .and_then(|hook| Option::<Pubkey>::from(hook.program_id));
+        if hook_program != Some(crate::ID) {
+            msg!("Execute - Mint's transfer hook does not point at this program");
+            return Err(TransferHookError::InvalidMint.into());
+        }
+    }
+
+    if *source_info.owner != spl_token_2022::ID {
+        msg!("Execute - Source is not a Token-2022 account");
+        return Err(TransferHookError::NotTransferring.into());
+    }
+    let source_data = source_info.try_borrow_data()?;
+    let source = StateWithExtensions::<TokenAccount>::unpack(&source_data)?;
+    let transferring = source
+        .get_extension::<TransferHookAccount>()
+        .map(|ext| bool::from(ext.transferring))
+        .unwrap_or(false);
+    if source.base.mint != mint_info.key() || !transferring {
+        msg!("Execute - Source account is not mid-transfer");
+        return Err(TransferHookError::NotTransferring.into());
+    }
+    Ok(())
+}
+
+/// Wallet that owns a Token-2022 token account.
+fn token_account_owner(info: &AccountInfo) -> Result<Pubkey> {
+    let data = info.try_borrow_data()?;
+    let account = StateWithExtensions::<TokenAccount>::unpack(&data)?;
+    Ok(account.base.owner)
+}
+
+/// Fail unless an existing account is owned by the stablecoin program. Missing (empty) accounts
+/// are left to the caller: an absent blacklist entry means "not blacklisted".
+fn verify_stablecoin_owner(info: &AccountInfo) -> Result<()> {
+    if !info.data_is_empty() && *info.owner != stablecoin::ID {
+        msg!("Execute - {} is not owned by the stablecoin program", info.key());
+        return Err(TransferHookError::InvalidAccountOwner.into());
+    }
+    Ok(())
+}
+
+/// Fail unless `info` is the stablecoin PDA for `seeds` (and, if it exists, owned by it).
+fn verify_stablecoin_pda(info: &AccountInfo, seeds: &[&[u8]], error: TransferHookError) -> Result<()> {
+    verify_stablecoin_owner(info)?;
+    let (expected, _bump) = Pubkey::find_program_address(seeds, &stablecoin::ID);
+    if info.key() != expected {
+        msg!("Execute - Unexpected account {} (expected {})", info.key(), expected);
+        return Err(error.into());
+    }
     Ok(())
 }
 
@@ -225,21 +754,181 @@ pub struct InitializeExtraAccountMetaList<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// CHECK: PDA checked via seeds
+    /// Master authority of the stablecoin, or its config PDA when invoked via CPI.
     #[account(
-        mut, 
-        seeds = [b"extra-account-metas", mint.key().as_ref()], 
+        constraint = authority.key() == config.master_authority
+            || authority.key() == config.key() @ TransferHookError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: PDA checked via seeds; created here and initialized as an ExtraAccountMetaList.
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas(config.enable_allowlist)?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
         bump
     )]
     pub extra_account_meta_list: AccountInfo<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        seeds::program = stablecoin::ID
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: PDA checked via seeds; must already be initialized by this program.
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        seeds::program = stablecoin::ID,
+        constraint = config.master_authority == authority.key() @ TransferHookError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
     pub mint: InterfaceAccount<'info, Mint>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeTransferLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        seeds::program = stablecoin::ID,
+        constraint = config.master_authority == authority.key() @ TransferHookError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: The wallet being limited; only its pubkey is used for the PDA.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TransferLimit::INIT_SPACE,
+        seeds = [TransferLimit::SEED_PREFIX, mint.key().as_ref(), wallet.key().as_ref()],
+        bump
+    )]
+    pub transfer_limit: Account<'info, TransferLimit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTransferLimit<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        seeds::program = stablecoin::ID,
+        constraint = config.master_authority == authority.key() @ TransferHookError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: The limited wallet; only its pubkey is used for the PDA.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TransferLimit::SEED_PREFIX, mint.key().as_ref(), wallet.key().as_ref()],
+        bump = transfer_limit.bump
+    )]
+    pub transfer_limit: Account<'info, TransferLimit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTransferLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [StablecoinConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = config.bump,
+        seeds::program = stablecoin::ID,
+        constraint = config.master_authority == authority.key() @ TransferHookError::Unauthorized
+    )]
+    pub config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: The limited wallet; only its pubkey is used for the PDA.
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [TransferLimit::SEED_PREFIX, mint.key().as_ref(), wallet.key().as_ref()],
+        bump = transfer_limit.bump
+    )]
+    pub transfer_limit: Account<'info, TransferLimit>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
 #[error_code]
 pub enum TransferHookError {
     #[msg("Account is blacklisted")]
     Blacklisted,
     #[msg("Account is not on the allowlist")]
     NotOnAllowlist,
+    #[msg("Transfers are paused")]
+    TransfersPaused,
+    #[msg("Signer is not the stablecoin's master authority or config PDA")]
+    Unauthorized,
+    #[msg("Transfer accounts do not match the mint's extra account meta list")]
+    InvalidExtraAccounts,
+    #[msg("Compliance account is not owned by the stablecoin program")]
+    InvalidAccountOwner,
+    #[msg("Blacklist account is not the PDA for this mint and wallet")]
+    InvalidBlacklistAccount,
+    #[msg("Allowlist account is not the PDA for this mint and wallet")]
+    InvalidAllowlistAccount,
+    #[msg("Config account is not the stablecoin config PDA for this mint")]
+    InvalidConfigAccount,
+    #[msg("Hook was not invoked by a Token-2022 transfer")]
+    NotTransferring,
+    #[msg("Mint is not a Token-2022 mint whose transfer hook is this program")]
+    InvalidMint,
+    #[msg("Transfer limit account is not this program's PDA for the source wallet")]
+    InvalidTransferLimitAccount,
+    #[msg("Transfer limit tier is out of range")]
+    InvalidTransferLimitTier,
+    #[msg("Transfer exceeds the wallet's per-transfer limit")]
+    PerTransferLimitExceeded,
+    #[msg("Transfer exceeds the wallet's daily limit")]
+    DailyTransferLimitExceeded,
+    #[msg("Allowlist policy account is not the stablecoin PDA for this mint")]
+    InvalidAllowlistPolicyAccount,
+    #[msg("Transfer exceeds the source wallet's allowlist tier cap")]
+    AllowlistTierAmountExceeded,
+    #[msg("Source wallet's allowlist tier may not send to the recipient's tier")]
+    CounterpartyTierNotAllowed,
+    #[msg("Transfer above the travel-rule threshold has no travel-rule memo")]
+    TravelRuleMemoMissing,
+    #[msg("Allowlist is enabled but the validation list has no allowlist policy account; call update_extra_account_meta_list")]
+    AllowlistPolicyMetaMissing,
 }
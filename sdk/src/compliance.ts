@@ -1,11 +1,68 @@
-import { PublicKey, SystemProgram } from "@solana/web3.js";
-import { BN } from "@coral-xyz/anchor";
+import {
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { BN, utils } from "@coral-xyz/anchor";
 import { SolanaStablecoin } from "./core";
 import {
   TOKEN_2022_PROGRAM_ID,
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 
+/** On-chain blacklist reason code (`BlacklistReason`). */
+export type BlacklistReasonCode =
+  | "unspecified"
+  | "sanctions"
+  | "fraud"
+  | "courtOrder"
+  | "lawEnforcement"
+  | "other";
+
+/**
+ * 32-byte case reference stored on a blacklist entry. Text (ticket id, case
+ * number, free-form reason) is stored as its SHA-256; raw 32 bytes are used
+ * as-is; nothing gives all zeros.
+ */
+export function toCaseReference(ref?: string | Uint8Array | null): number[] {
+  if (ref == null || ref === "") return new Array(32).fill(0);
+  if (typeof ref !== "string") {
+    if (ref.length !== 32) throw new Error("Case reference must be 32 bytes");
+    return Array.from(ref);
+  }
+  return Array.from(Buffer.from(utils.sha256.hash(ref), "hex"));
+}
+
+/** SPL Memo program (v2). */
+export const MEMO_PROGRAM_ID = new PublicKey(
+  "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+);
+
+/**
+ * Memo instruction the transfer hook accepts as travel-rule data for a
+ * transfer above the config's threshold: `travel-rule:<sha256 hex>`. Pass the
+ * originator/beneficiary payload (hashed here) or its 32-byte hash; only the
+ * hash goes on-chain. Add it directly before the transfer instruction; each
+ * transfer needs its own memo.
+ */
+export function createTravelRuleMemoInstruction(
+  payload: string | Uint8Array
+): TransactionInstruction {
+  let hash: string;
+  if (typeof payload === "string") {
+    hash = utils.sha256.hash(payload);
+  } else {
+    if (payload.length !== 32) throw new Error("Payload hash must be 32 bytes");
+    hash = Buffer.from(payload).toString("hex");
+  }
+  return new TransactionInstruction({
+    programId: MEMO_PROGRAM_ID,
+    keys: [],
+    data: Buffer.from(`travel-rule:${hash}`, "utf8"),
+  });
+}
+
 export class SSSComplianceModule {
   private sdk: SolanaStablecoin;
 
@@ -14,12 +71,18 @@ export class SSSComplianceModule {
   }
 
   /**
-   * Add an account to the blacklist. Optionally pass a reason for audit/logging (not stored on-chain).
+   * Add an account to the blacklist. `reason` is free text (kept off-chain);
+   * its SHA-256 is stored on-chain as the case reference unless an explicit
+   * `caseReference` is given. `reasonCode` is stored as-is.
    */
   async addToBlacklist(
     authority: PublicKey,
     accountToBlacklist: PublicKey,
-    reason?: string
+    reason?: string,
+    reasonCode: BlacklistReasonCode = "unspecified",
+    caseReference?: string | Uint8Array,
+    /** Unix seconds after which the listing lapses; omit for indefinite. */
+    expiresAt?: number | null
   ) {
     if (!this.sdk.mintAddress) throw new Error("Mint not set");
     const mint = this.sdk.mintAddress;
@@ -37,12 +100,44 @@ export class SSSComplianceModule {
       this.sdk.program.programId
     );
 
-    return this.sdk.program.methods.addToBlacklist().accounts({
+    return this.sdk.program.methods
+      .addToBlacklist(
+        { [reasonCode]: {} } as any,
+        toCaseReference(caseReference ?? reason),
+        expiresAt == null ? null : new BN(expiresAt)
+      )
+      .accounts({
+        blacklister: authority,
+        config,
+        roles: roleAccount,
+        targetAccount: accountToBlacklist,
+        blacklistEntry,
+        mint,
+        systemProgram: SystemProgram.programId,
+      } as any);
+  }
+
+  /**
+   * Grow a blacklist entry created before reason codes existed to the current
+   * layout (reason "unspecified", no case reference). Needed before it can be
+   * updated or removed; no-op if already current.
+   */
+  migrateBlacklistEntry(authority: PublicKey, account: PublicKey) {
+    if (!this.sdk.mintAddress) throw new Error("Mint not set");
+    const mint = this.sdk.mintAddress;
+    return this.sdk.program.methods.migrateBlacklistEntry().accounts({
       blacklister: authority,
-      config,
-      roles: roleAccount,
-      targetAccount: accountToBlacklist,
-      blacklistEntry,
+      config: SolanaStablecoin.getConfigPDA(mint, this.sdk.program.programId),
+      roles: SolanaStablecoin.getRoleAccountPDA(
+        mint,
+        this.sdk.program.programId
+      ),
+      targetAccount: account,
+      blacklistEntry: SolanaStablecoin.getBlacklistEntryPDA(
+        mint,
+        account,
+        this.sdk.program.programId
+      ),
       mint,
       systemProgram: SystemProgram.programId,
     } as any);
@@ -55,7 +150,12 @@ export class SSSComplianceModule {
   updateBlacklistEntry(
     authority: PublicKey,
     accountToUpdate: PublicKey,
-    isBlacklisted: boolean
+    isBlacklisted: boolean,
+    reasonCode: BlacklistReasonCode = "unspecified",
+    /** Text (hashed) or 32 raw bytes; see toCaseReference. */
+    caseReference?: string | Uint8Array,
+    /** Unix seconds after which the listing lapses; omit for indefinite. */
+    expiresAt?: number | null
   ) {
     if (!this.sdk.mintAddress) throw new Error("Mint not set");
     const mint = this.sdk.mintAddress;
@@ -74,7 +174,12 @@ export class SSSComplianceModule {
     );
 
     return this.sdk.program.methods
-      .updateBlacklistEntry(isBlacklisted)
+      .updateBlacklistEntry(
+        isBlacklisted,
+        { [reasonCode]: {} } as any,
+        toCaseReference(caseReference),
+        expiresAt == null ? null : new BN(expiresAt)
+      )
       .accounts({
         blacklister: authority,
         config,
@@ -115,6 +220,35 @@ export class SSSComplianceModule {
     } as any);
   }
 
+  /**
+   * Close a blacklist entry PDA and return its rent to `recipient` (defaults
+   * to `authority`). A missing entry is treated as not blacklisted.
+   */
+  closeBlacklistEntry(
+    authority: PublicKey,
+    account: PublicKey,
+    recipient: PublicKey = authority
+  ) {
+    if (!this.sdk.mintAddress) throw new Error("Mint not set");
+    const mint = this.sdk.mintAddress;
+    return this.sdk.program.methods.closeBlacklistEntry().accounts({
+      blacklister: authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.sdk.program.programId),
+      roles: SolanaStablecoin.getRoleAccountPDA(
+        mint,
+        this.sdk.program.programId
+      ),
+      targetAccount: account,
+      blacklistEntry: SolanaStablecoin.getBlacklistEntryPDA(
+        mint,
+        account,
+        this.sdk.program.programId
+      ),
+      recipient,
+      mint,
+    } as any);
+  }
+
   async seize(
     authority: PublicKey,
     from: PublicKey,
@@ -169,6 +303,16 @@ export class SSSComplianceModule {
       to,
       this.sdk.program.programId
     );
+    const authorityBlacklist = SolanaStablecoin.getBlacklistEntryPDA(
+      mint,
+      config,
+      this.sdk.program.programId
+    );
+    const sourceTransferLimit = SolanaStablecoin.getTransferLimitPDA(
+      mint,
+      from,
+      this.sdk.transferHookProgram!.programId
+    );
 
     return this.sdk.program.methods.seize(new BN(amount)).accounts({
       seizer: authority,
@@ -186,13 +330,23 @@ export class SSSComplianceModule {
       configAllowlist: config,
       sourceAllowlist,
       destAllowlist,
+      authorityBlacklist,
+      sourceTransferLimit,
+      allowlistPolicy: SolanaStablecoin.getAllowlistPolicyPDA(
+        mint,
+        this.sdk.program.programId
+      ),
+      instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
     } as any);
   }
 
-  async initializeTransferHookExtraAccounts(
-    authority: PublicKey,
-    enableAllowlist: boolean = false
-  ) {
+  /**
+   * Create the transfer hook's extra-account-metas for the mint, laid out from
+   * the config (allowlist accounts included iff `enableAllowlist`). `authority`
+   * must be the stablecoin's master authority and pays for the account; an
+   * existing list is changed with updateTransferHookExtraAccounts instead.
+   */
+  async initializeTransferHookExtraAccounts(authority: PublicKey) {
     if (!this.sdk.transferHookProgram) {
       throw new Error("Transfer Hook Program not provided to SDK");
     }
@@ -205,12 +359,143 @@ export class SSSComplianceModule {
     );
 
     return this.sdk.transferHookProgram.methods
-      .initializeExtraAccountMetaList(enableAllowlist)
+      .initializeExtraAccountMetaList()
       .accounts({
         payer: authority,
+        authority,
         extraAccountMetaList,
+        config: SolanaStablecoin.getConfigPDA(
+          mint,
+          this.sdk.program.programId
+        ),
         mint,
         systemProgram: SystemProgram.programId,
       } as any);
   }
+
+  /**
+   * Rebuild the transfer hook's extra-account-metas from the current config
+   * (allowlist accounts included iff `enableAllowlist`). Master authority only;
+   * rent is topped up from or refunded to `authority`.
+   */
+  updateTransferHookExtraAccounts(authority: PublicKey) {
+    if (!this.sdk.transferHookProgram) {
+      throw new Error("Transfer Hook Program not provided to SDK");
+    }
+    if (!this.sdk.mintAddress) throw new Error("Mint not set");
+    const mint = this.sdk.mintAddress;
+    return this.sdk.transferHookProgram.methods
+      .updateExtraAccountMetaList()
+      .accounts({
+        authority,
+        extraAccountMetaList: SolanaStablecoin.getExtraAccountMetaListPDA(
+          mint,
+          this.sdk.transferHookProgram.programId
+        ),
+        config: SolanaStablecoin.getConfigPDA(
+          mint,
+          this.sdk.program.programId
+        ),
+        mint,
+        systemProgram: SystemProgram.programId,
+      } as any);
+  }
+
+  /**
+   * Give `wallet` outbound transfer caps enforced by the hook. `tier` selects
+   * a config transfer limit tier; `dailyLimit` / `perTransferLimit` override
+   * it when given (0 = no cap). Master authority only; `authority` pays rent.
+   */
+  initializeTransferLimit(
+    authority: PublicKey,
+    wallet: PublicKey,
+    tier: number,
+    dailyLimit?: number | string | bigint | null,
+    perTransferLimit?: number | string | bigint | null
+  ) {
+    if (!this.sdk.transferHookProgram) {
+      throw new Error("Transfer Hook Program not provided to SDK");
+    }
+    if (!this.sdk.mintAddress) throw new Error("Mint not set");
+    const mint = this.sdk.mintAddress;
+    return this.sdk.transferHookProgram.methods
+      .initializeTransferLimit(
+        tier,
+        dailyLimit == null ? null : new BN(dailyLimit.toString()),
+        perTransferLimit == null ? null : new BN(perTransferLimit.toString())
+      )
+      .accounts({
+        authority,
+        config: SolanaStablecoin.getConfigPDA(
+          mint,
+          this.sdk.program.programId
+        ),
+        wallet,
+        transferLimit: SolanaStablecoin.getTransferLimitPDA(
+          mint,
+          wallet,
+          this.sdk.transferHookProgram.programId
+        ),
+        mint,
+        systemProgram: SystemProgram.programId,
+      } as any);
+  }
+
+  /** Change a wallet's transfer limit tier or overrides; usage so far keeps counting. */
+  updateTransferLimit(
+    authority: PublicKey,
+    wallet: PublicKey,
+    tier: number,
+    dailyLimit?: number | string | bigint | null,
+    perTransferLimit?: number | string | bigint | null
+  ) {
+    if (!this.sdk.transferHookProgram) {
+      throw new Error("Transfer Hook Program not provided to SDK");
+    }
+    if (!this.sdk.mintAddress) throw new Error("Mint not set");
+    const mint = this.sdk.mintAddress;
+    return this.sdk.transferHookProgram.methods
+      .updateTransferLimit(
+        tier,
+        dailyLimit == null ? null : new BN(dailyLimit.toString()),
+        perTransferLimit == null ? null : new BN(perTransferLimit.toString())
+      )
+      .accounts({
+        authority,
+        config: SolanaStablecoin.getConfigPDA(
+          mint,
+          this.sdk.program.programId
+        ),
+        wallet,
+        transferLimit: SolanaStablecoin.getTransferLimitPDA(
+          mint,
+          wallet,
+          this.sdk.transferHookProgram.programId
+        ),
+        mint,
+      } as any);
+  }
+
+  /**
+   * Close a wallet's transfer limit and refund its rent to `authority`. The
+   * wallet is uncapped afterwards, like one that never had a limit.
+   */
+  closeTransferLimit(authority: PublicKey, wallet: PublicKey) {
+    if (!this.sdk.transferHookProgram) {
+      throw new Error("Transfer Hook Program not provided to SDK");
+    }
+    if (!this.sdk.mintAddress) throw new Error("Mint not set");
+    const mint = this.sdk.mintAddress;
+    return this.sdk.transferHookProgram.methods.closeTransferLimit().accounts({
+      authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.sdk.program.programId),
+      wallet,
+      transferLimit: SolanaStablecoin.getTransferLimitPDA(
+        mint,
+        wallet,
+        this.sdk.transferHookProgram.programId
+      ),
+      mint,
+    } as any);
+  }
 }
@@ -5,6 +5,7 @@
  * configure, apply pending, transfer, and withdraw.
  */
 
+import { BN } from "@coral-xyz/anchor";
 import {
   PublicKey,
   TransactionInstruction,
@@ -43,6 +44,60 @@ function buildConfidentialInstructionData(
   return buf;
 }
 
+/**
+ * 32-byte ElGamal pubkey as the program expects it. Accepts raw bytes or the
+ * base64 form the Solana CLI and zk-sdk print.
+ */
+export function toElGamalPubkey(key: Uint8Array | string): number[] {
+  const bytes = typeof key === "string" ? Buffer.from(key, "base64") : key;
+  if (bytes.length !== 32) throw new Error("ElGamal pubkey must be 32 bytes");
+  return Array.from(bytes);
+}
+
+/**
+ * 36-byte authenticated-encryption ciphertext (decryptable supply or balance).
+ * Accepts raw bytes or base64, like `toElGamalPubkey`.
+ */
+export function toAeCiphertext(ciphertext: Uint8Array | string): number[] {
+  const bytes =
+    typeof ciphertext === "string"
+      ? Buffer.from(ciphertext, "base64")
+      : ciphertext;
+  if (bytes.length !== 36) throw new Error("AE ciphertext must be 36 bytes");
+  return Array.from(bytes);
+}
+
+/**
+ * 32-byte Pedersen opening (commitment randomness). Accepts raw bytes or
+ * base64, like `toElGamalPubkey`.
+ */
+export function toPedersenOpening(opening: Uint8Array | string): number[] {
+  const bytes =
+    typeof opening === "string" ? Buffer.from(opening, "base64") : opening;
+  if (bytes.length !== 32) throw new Error("Pedersen opening must be 32 bytes");
+  return Array.from(bytes);
+}
+
+/**
+ * Pre-verified zk proof context state accounts for a confidential mint or
+ * burn, created with the ZK ElGamal proof program before the call, plus the
+ * openings of the lo/hi amount commitments from the same proof generation.
+ * The program checks the openings against the plaintext amount.
+ */
+export interface ConfidentialMintBurnProofs {
+  equality: PublicKey;
+  ciphertextValidity: PublicKey;
+  range: PublicKey;
+  amountOpening: { lo: Uint8Array | string; hi: Uint8Array | string };
+}
+
+function amountOpeningArg(proofs: ConfidentialMintBurnProofs) {
+  return {
+    lo: toPedersenOpening(proofs.amountOpening.lo),
+    hi: toPedersenOpening(proofs.amountOpening.hi),
+  };
+}
+
 /**
  * SSS-3 confidential operations. Requires mint with ConfidentialTransferMint
  * and (when allowlist enabled) allowlist check before deposit.
@@ -222,6 +277,154 @@ export class SSS3ConfidentialModule {
     });
   }
 
+  /**
+   * Approve a token account's confidential configuration (allowlister or
+   * master authority). Required when the mint does not auto-approve new
+   * accounts; the config PDA signs as the extension authority.
+   */
+  approveAccount(
+    authority: PublicKey,
+    owner: PublicKey,
+    tokenAccount?: PublicKey
+  ) {
+    const token =
+      tokenAccount ??
+      getAssociatedTokenAddressSync(
+        this.mint,
+        owner,
+        true,
+        this.tokenProgramId
+      );
+    const programId = this.sdk.program.programId;
+    return this.sdk.program.methods.approveConfidentialAccount().accounts({
+      authority,
+      config: StablecoinClass.getConfigPDA(this.mint, programId),
+      roles: StablecoinClass.getRoleAccountPDA(this.mint, programId),
+      mint: this.mint,
+      tokenAccount: token,
+      tokenProgram: this.tokenProgramId,
+    } as any);
+  }
+
+  /**
+   * Rotate the auditor ElGamal key (null removes it) and set whether new
+   * confidential accounts are auto-approved. Master authority only.
+   */
+  updateMint(
+    authority: PublicKey,
+    autoApproveNewAccounts: boolean,
+    auditorElGamalPubkey: Uint8Array | string | null
+  ) {
+    const programId = this.sdk.program.programId;
+    return this.sdk.program.methods
+      .updateConfidentialMint(
+        autoApproveNewAccounts,
+        auditorElGamalPubkey == null
+          ? null
+          : toElGamalPubkey(auditorElGamalPubkey)
+      )
+      .accounts({
+        admin: authority,
+        config: StablecoinClass.getConfigPDA(this.mint, programId),
+        mint: this.mint,
+        tokenProgram: this.tokenProgramId,
+      } as any);
+  }
+
+  /**
+   * Mint into the recipient's pending confidential balance (active minter).
+   * `amount` is the plaintext the proofs encrypt; the program checks it
+   * against `proofs.amountOpening`, then charges the minter's quota and the
+   * supply cap like a public mint. Requires the ConfidentialMintBurn extension.
+   */
+  confidentialMint(
+    minter: PublicKey,
+    to: PublicKey,
+    amount: number | string,
+    newDecryptableSupply: Uint8Array | string,
+    proofs: ConfidentialMintBurnProofs,
+    tokenAccount?: PublicKey
+  ) {
+    const token =
+      tokenAccount ??
+      getAssociatedTokenAddressSync(this.mint, to, true, this.tokenProgramId);
+    const programId = this.sdk.program.programId;
+    return this.sdk.program.methods
+      .confidentialMint(
+        new BN(amount),
+        amountOpeningArg(proofs),
+        toAeCiphertext(newDecryptableSupply)
+      )
+      .accounts({
+        minter,
+        config: StablecoinClass.getConfigPDA(this.mint, programId),
+        minterConfig: StablecoinClass.getMinterPDA(this.mint, minter, programId),
+        mint: this.mint,
+        toAccount: token,
+        equalityProof: proofs.equality,
+        ciphertextValidityProof: proofs.ciphertextValidity,
+        rangeProof: proofs.range,
+        tokenProgram: this.tokenProgramId,
+      } as any);
+  }
+
+  /**
+   * Burn from the burner's own confidential available balance (burner role or
+   * master authority). `amount` is the plaintext the proofs encrypt and is
+   * checked against `proofs.amountOpening`.
+   */
+  confidentialBurn(
+    burner: PublicKey,
+    amount: number | string,
+    newDecryptableAvailableBalance: Uint8Array | string,
+    proofs: ConfidentialMintBurnProofs,
+    tokenAccount?: PublicKey
+  ) {
+    const token =
+      tokenAccount ??
+      getAssociatedTokenAddressSync(
+        this.mint,
+        burner,
+        true,
+        this.tokenProgramId
+      );
+    const programId = this.sdk.program.programId;
+    return this.sdk.program.methods
+      .confidentialBurn(
+        new BN(amount),
+        amountOpeningArg(proofs),
+        toAeCiphertext(newDecryptableAvailableBalance)
+      )
+      .accounts({
+        burner,
+        config: StablecoinClass.getConfigPDA(this.mint, programId),
+        roles: StablecoinClass.getRoleAccountPDA(this.mint, programId),
+        mint: this.mint,
+        fromAccount: token,
+        equalityProof: proofs.equality,
+        ciphertextValidityProof: proofs.ciphertextValidity,
+        rangeProof: proofs.range,
+        tokenProgram: this.tokenProgramId,
+      } as any);
+  }
+
+  /**
+   * Overwrite the config's tracked confidential supply (master authority).
+   * Burns made with Token-2022's confidential `Burn` directly bypass the
+   * program, so the counter drifts upward; decrypt the mint's confidential
+   * supply with the supply ElGamal key and pass it here to free the cap.
+   */
+  reconcileSupply(authority: PublicKey, confidentialSupply: number | string) {
+    const programId = this.sdk.program.programId;
+    return this.sdk.program.methods
+      .reconcileConfidentialSupply(new BN(confidentialSupply))
+      .accounts({
+        admin: authority,
+        config: StablecoinClass.getConfigPDA(this.mint, programId),
+        mint: this.mint,
+      } as any);
+  }
+
   /**
    * Withdraw from confidential available balance to public balance.
    * Caller supplies instruction data (amount + proof encoding) and optional
@@ -3,6 +3,8 @@ import {
   Keypair,
   PublicKey,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
   Transaction,
   sendAndConfirmTransaction,
   Signer,
@@ -16,7 +18,12 @@ import {
 } from "@solana/spl-token";
 import type { Stablecoin } from "../../target/types/stablecoin";
 import type { TransferHook } from "../../target/types/transfer_hook";
-import { SSS3ConfidentialModule } from "./confidential";
+import {
+  SSS3ConfidentialModule,
+  toAeCiphertext,
+  toElGamalPubkey,
+} from "./confidential";
+import { ORACLE_PROGRAM_ID } from "./oracle";
 
 /** Config for creating a new stablecoin (name, symbol, etc.). Stored on-chain in StablecoinConfig. */
 export interface StablecoinConfig {
@@ -26,12 +33,58 @@ export interface StablecoinConfig {
   decimals: number;
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
-  /** Policy flag: new accounts start frozen when true. Stored on-chain only. */
+  /** New token accounts start frozen when true (Token-2022 DefaultAccountState extension). */
   defaultAccountFrozen?: boolean;
   /** SSS-3: enable Token-2022 confidential transfer mint extension. */
   enableConfidentialTransfers?: boolean;
   /** SSS-3: restrict transfers to allowlisted wallets when enabled. */
   enableAllowlist?: boolean;
+  /**
+   * SSS-3: new confidential accounts are usable without approval (default
+   * true). When false, approve each with `approveAccount` on the confidential module.
+   */
+  autoApproveConfidentialAccounts?: boolean;
+  /** SSS-3: auditor ElGamal pubkey (32 bytes or base64); can decrypt every confidential transfer. */
+  auditorElGamalPubkey?: Uint8Array | string | null;
+  /**
+   * SSS-3: add the ConfidentialMintBurn extension so `confidentialMint` /
+   * `confidentialBurn` work. Needs `enableConfidentialTransfers`. Keys are raw
+   * bytes or base64; `decryptableSupply` is zero encrypted under the supply AES key.
+   */
+  confidentialMintBurn?: {
+    supplyElGamalPubkey: Uint8Array | string;
+    decryptableSupply: Uint8Array | string;
+  } | null;
+}
+
+/**
+ * Pause bitmask (`StablecoinConfig::PAUSE_*`); combine with `|`. Transfers are
+ * halted by the transfer hook; seizures by the config PDA are exempt. `default`
+ * is what `pause()` halts without flags: everything but seize, which stays
+ * available to compliance during an emergency unless paused explicitly.
+ */
+export const PauseFlags = {
+  mint: 1,
+  burn: 2,
+  transfer: 4,
+  seize: 8,
+  default: 7,
+  all: 15,
+} as const;
+
+/** On-chain pause reason code (`PauseReason`). */
+export type PauseReasonCode =
+  | "unspecified"
+  | "incident"
+  | "exploit"
+  | "maintenance"
+  | "compliance"
+  | "other";
+
+/** Outbound transfer caps for one tier (base units); 0 = no cap. */
+export interface TransferLimitTier {
+  dailyLimit: bigint;
+  perTransferLimit: bigint;
 }
 
 /** On-chain config account (decimals, pause, flags, name, symbol, uri). */
@@ -43,23 +96,74 @@ export interface StablecoinConfigAccount {
   symbol: string;
   uri: string;
   decimals: number;
+  /** True when any operation is paused; see pauseFlags. */
   isPaused: boolean;
+  /** Bitmask of paused operations (PauseFlags). */
+  pauseFlags: number;
+  /** Reason given by the most recent pause. */
+  pauseReason: PauseReasonCode;
+  /** Unix time of the most recent pause; 0 if never paused. */
+  pausedAt: number;
   enablePermanentDelegate: boolean;
   enableTransferHook: boolean;
   defaultAccountFrozen: boolean;
   enableConfidentialTransfers: boolean;
   enableAllowlist: boolean;
+  /** Proposed master authority awaiting accept_authority, if any. */
+  pendingAuthority: PublicKey | null;
+  /** Global supply cap enforced on mint (base units); null = uncapped. */
+  maxSupply: bigint | null;
+  /** Caps the transfer hook applies to wallets with a TransferLimit account, by tier. */
+  transferLimitTiers: TransferLimitTier[];
+  /** Transfers above this (base units) need a travel-rule memo; null = disabled. */
+  travelRuleThreshold: bigint | null;
+  /** Mint carries ConfidentialMintBurn (confidential mint/burn instructions usable). */
+  enableConfidentialMintBurn: boolean;
+  /** Net confidential mint minus burn (base units); counts toward maxSupply. */
+  confidentialSupply: bigint;
+  /** Switchboard queue and feed mintAtOraclePrice prices against; null = disabled. */
+  oracleFeed: OracleFeed | null;
+}
+
+/** Switchboard queue and 32-byte feed hash pinned for oracle-priced minting. */
+export interface OracleFeed {
+  queue: PublicKey;
+  feedId: Uint8Array;
 }
 
-/** On-chain role account (burner, pauser, blacklister, seizer). */
+/** On-chain role account (burner, pauser, blacklister, seizer) and pending proposals. */
 export interface RoleAccountData {
   bump: number;
   burner: PublicKey;
   pauser: PublicKey;
   blacklister: PublicKey;
   seizer: PublicKey;
+  minterController: PublicKey;
+  allowlister: PublicKey;
+  pendingBurner: PublicKey | null;
+  pendingPauser: PublicKey | null;
+  pendingBlacklister: PublicKey | null;
+  pendingSeizer: PublicKey | null;
+  pendingMinterController: PublicKey | null;
+  pendingAllowlister: PublicKey | null;
 }
 
+/** Authority slot for the two-step propose/accept/cancel handover. */
+export type AuthorityRole =
+  | "master"
+  | "burner"
+  | "pauser"
+  | "blacklister"
+  | "seizer"
+  | "minterController"
+  | "allowlister";
+
+/**
+ * How a minter's issuance is limited: a quota over a rolling window, or a
+ * decrementing allowance topped up by the minter controller.
+ */
+export type MintQuotaMode = "rollingWindow" | "allowance";
+
 const DEFAULT_STABLECOIN_PROGRAM_ID =
   "3zFReCtrBsjMZNabaV4vJSaCHtTpFtApkWMjrr5gAeeM";
 const DEFAULT_TRANSFER_HOOK_PROGRAM_ID =
@@ -163,6 +267,24 @@ export class SolanaStablecoin {
     )[0];
   }
 
+  static getAllowlistPolicyPDA(mint: PublicKey, programId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("allowlist-policy"), mint.toBuffer()],
+      programId
+    )[0];
+  }
+
+  static getTransferLimitPDA(
+    mint: PublicKey,
+    wallet: PublicKey,
+    transferHookProgramId: PublicKey
+  ): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("transfer-limit"), mint.toBuffer(), wallet.toBuffer()],
+      transferHookProgramId
+    )[0];
+  }
+
   static getExtraAccountMetaListPDA(
     mint: PublicKey,
     transferHookProgramId: PublicKey
@@ -200,12 +322,32 @@ export class SolanaStablecoin {
         config.symbol,
         config.uri,
         config.decimals,
-        config.enablePermanentDelegate,
-        config.enableTransferHook,
-        config.defaultAccountFrozen ?? false,
-        config.enableConfidentialTransfers ?? false,
-        config.enableAllowlist ?? false,
-        transferHookProgramId || null
+        {
+          enablePermanentDelegate: config.enablePermanentDelegate,
+          enableTransferHook: config.enableTransferHook,
+          defaultAccountFrozen: config.defaultAccountFrozen ?? false,
+          enableConfidentialTransfers:
+            config.enableConfidentialTransfers ?? false,
+          enableAllowlist: config.enableAllowlist ?? false,
+          transferHookProgramId: transferHookProgramId || null,
+          autoApproveConfidentialAccounts:
+            config.autoApproveConfidentialAccounts ?? true,
+          auditorElgamalPubkey:
+            config.auditorElGamalPubkey == null
+              ? null
+              : toElGamalPubkey(config.auditorElGamalPubkey),
+          confidentialMintBurn:
+            config.confidentialMintBurn == null
+              ? null
+              : {
+                  supplyElgamalPubkey: toElGamalPubkey(
+                    config.confidentialMintBurn.supplyElGamalPubkey
+                  ),
+                  decryptableSupply: toAeCiphertext(
+                    config.confidentialMintBurn.decryptableSupply
+                  ),
+                },
+        }
       )
       .accounts({
         admin: authority,
@@ -219,6 +361,35 @@ export class SolanaStablecoin {
     return builder;
   }
 
+  /**
+   * Update on-chain token metadata (master authority). `name` / `uri` are mirrored into
+   * StablecoinConfig; `additionalMetadata` key/value pairs live only in the mint's TokenMetadata.
+   */
+  updateMetadata(
+    authority: PublicKey,
+    update: {
+      name?: string | null;
+      uri?: string | null;
+      additionalMetadata?: { key: string; value: string }[];
+    }
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods
+      .updateMetadata(
+        update.name ?? null,
+        update.uri ?? null,
+        update.additionalMetadata ?? []
+      )
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        mint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as any);
+  }
+
   async mint(
     authority: PublicKey,
     to: PublicKey,
@@ -252,6 +423,50 @@ export class SolanaStablecoin {
     } as any);
   }
 
+  /**
+   * Mint whatever the oracle program prices `pegAmount` (smallest peg units) at,
+   * under the minter's usual quota. The quote must come from the queue and feed
+   * set with `setOracleFeed`. The transaction must start with the Switchboard
+   * update and Ed25519 verify instructions (indexes 0 and 1); add them with
+   * `.preInstructions([...])` on the returned builder.
+   */
+  async mintAtOraclePrice(
+    authority: PublicKey,
+    to: PublicKey,
+    pegAmount: number | string | bigint,
+    oracleProgramId: PublicKey = ORACLE_PROGRAM_ID
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    const { oracleFeed } = await this.getConfig();
+    if (!oracleFeed) throw new Error("No oracle feed set (see setOracleFeed)");
+    const destinationAta = getAssociatedTokenAddressSync(
+      mint,
+      to,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    return this.program.methods
+      .mintAtOraclePrice(new BN(pegAmount.toString()))
+      .accounts({
+        minter: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        minterConfig: SolanaStablecoin.getMinterPDA(
+          mint,
+          authority,
+          this.program.programId
+        ),
+        mint,
+        toAccount: destinationAta,
+        queue: oracleFeed.queue,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        oracleProgram: oracleProgramId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      } as any);
+  }
+
   /**
    * Build mint instructions including createAssociatedTokenAccountInstruction if the
    * recipient's Token-2022 ATA does not exist. Fetches ATA info via RPC.
@@ -380,8 +595,33 @@ export class SolanaStablecoin {
     } as any);
   }
 
-  /** SSS-3: Add a wallet to the allowlist (master authority only). */
-  async addToAllowlist(authority: PublicKey, wallet: PublicKey) {
+  /**
+   * Set the mint's DefaultAccountState (blacklister or master authority). When `frozen` is true,
+   * new token accounts start frozen until thawed (e.g. KYC-gated issuance).
+   */
+  setDefaultAccountState(authority: PublicKey, frozen: boolean) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.setDefaultAccountState(frozen).accounts({
+      blacklister: authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
+      mint,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    } as any);
+  }
+
+  /**
+   * SSS-3: Add a wallet to the allowlist (allowlister or master authority).
+   * `expiresAt` (unix seconds) makes the approval lapse, e.g. at KYC renewal.
+   * `tier` selects the wallet's rules in the allowlist policy (default 0).
+   */
+  async addToAllowlist(
+    authority: PublicKey,
+    wallet: PublicKey,
+    expiresAt?: number | null,
+    tier: number = 0
+  ) {
     if (!this.mintAddress) throw new Error("Mint not set");
     const mint = this.mintAddress;
     const config = SolanaStablecoin.getConfigPDA(mint, this.program.programId);
@@ -391,17 +631,20 @@ export class SolanaStablecoin {
       this.program.programId
     );
 
-    return this.program.methods.addToAllowlist().accounts({
-      authority,
-      config,
-      mint,
-      wallet,
-      allowlistEntry,
-      systemProgram: SystemProgram.programId,
-    } as any);
+    return this.program.methods
+      .addToAllowlist(expiresAt == null ? null : new BN(expiresAt), tier)
+      .accounts({
+        authority,
+        config,
+        mint,
+        roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
+        wallet,
+        allowlistEntry,
+        systemProgram: SystemProgram.programId,
+      } as any);
   }
 
-  /** SSS-3: Remove a wallet from the allowlist (master authority only). */
+  /** SSS-3: Remove a wallet from the allowlist (allowlister or master authority). */
   async removeFromAllowlist(authority: PublicKey, wallet: PublicKey) {
     if (!this.mintAddress) throw new Error("Mint not set");
     const mint = this.mintAddress;
@@ -416,11 +659,120 @@ export class SolanaStablecoin {
       authority,
       config,
       mint,
+      roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
       wallet,
       allowlistEntry,
     } as any);
   }
 
+  /**
+   * SSS-3: Close an allowlist entry PDA and return its rent to `recipient`
+   * (defaults to `authority`). Allowlister or master authority.
+   */
+  closeAllowlistEntry(
+    authority: PublicKey,
+    wallet: PublicKey,
+    recipient: PublicKey = authority
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.closeAllowlistEntry().accounts({
+      authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      mint,
+      roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
+      wallet,
+      allowlistEntry: SolanaStablecoin.getAllowlistEntryPDA(
+        mint,
+        wallet,
+        this.program.programId
+      ),
+      recipient,
+    } as any);
+  }
+
+  /**
+   * SSS-3: Turn allowlist enforcement on or off (master authority). Follow with
+   * `compliance.updateTransferHookExtraAccounts` (same transaction is fine) so
+   * the hook's account list matches.
+   */
+  setAllowlistEnabled(authority: PublicKey, enabled: boolean) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.setAllowlistEnabled(enabled).accounts({
+      admin: authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      mint,
+    } as any);
+  }
+
+  /**
+   * SSS-3: Create the mint's allowlist policy (master authority). Every tier
+   * starts unrestricted; tighten them with setAllowlistTier.
+   */
+  initializeAllowlistPolicy(authority: PublicKey) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.initializeAllowlistPolicy().accounts({
+      admin: authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      mint,
+      allowlistPolicy: SolanaStablecoin.getAllowlistPolicyPDA(
+        mint,
+        this.program.programId
+      ),
+      systemProgram: SystemProgram.programId,
+    } as any);
+  }
+
+  /**
+   * SSS-3: Set what wallets in `tier` may send (master authority): a
+   * per-transfer cap (0 = none) and the tiers they may send to.
+   */
+  setAllowlistTier(
+    authority: PublicKey,
+    tier: number,
+    maxTransferAmount: number | string | bigint,
+    allowedRecipientTiers: number[]
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    const mask = allowedRecipientTiers.reduce((acc, t) => acc | (1 << t), 0);
+    return this.program.methods
+      .setAllowlistTier(tier, new BN(maxTransferAmount.toString()), mask)
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        mint,
+        allowlistPolicy: SolanaStablecoin.getAllowlistPolicyPDA(
+          mint,
+          this.program.programId
+        ),
+      } as any);
+  }
+
+  /**
+   * SSS-3: Grow an allowlist entry created before expiry or tiers existed to
+   * the current layout. Needed before it can be updated; no-op if already current.
+   */
+  migrateAllowlistEntry(authority: PublicKey, wallet: PublicKey) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.migrateAllowlistEntry().accounts({
+      authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
+      wallet,
+      allowlistEntry: SolanaStablecoin.getAllowlistEntryPDA(
+        mint,
+        wallet,
+        this.program.programId
+      ),
+      mint,
+      systemProgram: SystemProgram.programId,
+    } as any);
+  }
+
   /**
    * Update allowlist entry (e.g. set is_allowed = true after remove).
    * Use when addToAllowlist fails with AlreadyInitialized.
@@ -428,7 +780,10 @@ export class SolanaStablecoin {
   updateAllowlistEntry(
     authority: PublicKey,
     wallet: PublicKey,
-    isAllowed: boolean
+    isAllowed: boolean,
+    /** Unix seconds; omit for no expiry. */
+    expiresAt?: number | null,
+    tier: number = 0
   ) {
     if (!this.mintAddress) throw new Error("Mint not set");
     const mint = this.mintAddress;
@@ -440,17 +795,30 @@ export class SolanaStablecoin {
     );
 
     return this.program.methods
-      .updateAllowlistEntry(isAllowed)
+      .updateAllowlistEntry(
+        isAllowed,
+        expiresAt == null ? null : new BN(expiresAt),
+        tier
+      )
       .accounts({
         authority,
         config,
         mint,
+        roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
         wallet,
         allowlistEntry,
       } as any);
   }
 
-  async pause(authority: PublicKey) {
+  /**
+   * Pause the operations in `flags` (default: mint, burn and transfer; seize
+   * only when included) on top of any already paused, recording `reason`.
+   */
+  async pause(
+    authority: PublicKey,
+    flags: number = PauseFlags.default,
+    reason: PauseReasonCode = "unspecified"
+  ) {
     if (!this.mintAddress) throw new Error("Mint not set");
     const mint = this.mintAddress;
     const roleAccount = SolanaStablecoin.getRoleAccountPDA(
@@ -458,15 +826,18 @@ export class SolanaStablecoin {
       this.program.programId
     );
 
-    return this.program.methods.pause().accounts({
-      pauser: authority,
-      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
-      roles: roleAccount,
-      mint,
-    } as any);
+    return this.program.methods
+      .pause(flags, { [reason]: {} } as any)
+      .accounts({
+        pauser: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        roles: roleAccount,
+        mint,
+      } as any);
   }
 
-  async unpause(authority: PublicKey) {
+  /** Resume the operations in `flags` (default: everything). */
+  async unpause(authority: PublicKey, flags: number = PauseFlags.all) {
     if (!this.mintAddress) throw new Error("Mint not set");
     const mint = this.mintAddress;
     const roleAccount = SolanaStablecoin.getRoleAccountPDA(
@@ -474,12 +845,14 @@ export class SolanaStablecoin {
       this.program.programId
     );
 
-    return this.program.methods.unpause().accounts({
-      pauser: authority,
-      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
-      roles: roleAccount,
-      mint,
-    } as any);
+    return this.program.methods
+      .unpause(flags)
+      .accounts({
+        pauser: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        roles: roleAccount,
+        mint,
+      } as any);
   }
 
   /**
@@ -490,7 +863,11 @@ export class SolanaStablecoin {
     authority: PublicKey,
     minter: PublicKey,
     isActive: boolean,
-    dailyLimit: number | string
+    dailyLimit: number | string,
+    /** Rolling quota window in seconds, a multiple of 24 (default 86400 on-chain). */
+    windowSeconds?: number | null,
+    /** Quota mode (default rollingWindow). Allowance mode starts at zero allowance. */
+    quotaMode: MintQuotaMode = "rollingWindow"
   ) {
     if (!this.mintAddress) throw new Error("Mint not set");
     const mint = this.mintAddress;
@@ -501,7 +878,12 @@ export class SolanaStablecoin {
     );
 
     return this.program.methods
-      .configureMinter(isActive, new BN(dailyLimit))
+      .configureMinter(
+        isActive,
+        new BN(dailyLimit),
+        windowSeconds ?? null,
+        { [quotaMode]: {} } as any
+      )
       .accounts({
         admin: authority,
         config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
@@ -513,14 +895,18 @@ export class SolanaStablecoin {
   }
 
   /**
-   * Update an existing minter's active flag and quota.
+   * Update an existing minter's active flag, quota and (optionally) rolling window length.
    * Use when addMinter (configureMinter) fails with AlreadyInitialized.
    */
   updateMinterQuota(
     authority: PublicKey,
     minter: PublicKey,
     isActive: boolean,
-    dailyLimit: number | string
+    dailyLimit: number | string,
+    /** New rolling quota window in seconds (a multiple of 24); omit to keep the current one. */
+    windowSeconds?: number | null,
+    /** New quota mode; omit to keep the current mode. */
+    quotaMode?: MintQuotaMode | null
   ) {
     if (!this.mintAddress) throw new Error("Mint not set");
     const mint = this.mintAddress;
@@ -531,7 +917,12 @@ export class SolanaStablecoin {
     );
 
     return this.program.methods
-      .updateMinter(isActive, new BN(dailyLimit))
+      .updateMinter(
+        isActive,
+        new BN(dailyLimit),
+        windowSeconds ?? null,
+        quotaMode ? ({ [quotaMode]: {} } as any) : null
+      )
       .accounts({
         admin: authority,
         config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
@@ -541,6 +932,76 @@ export class SolanaStablecoin {
       } as any);
   }
 
+  /**
+   * Close a minter's config PDA and return its rent to `recipient` (defaults
+   * to `authority`). Master authority only.
+   */
+  removeMinter(
+    authority: PublicKey,
+    minter: PublicKey,
+    recipient: PublicKey = authority
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.removeMinter().accounts({
+      admin: authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      minter,
+      minterConfig: SolanaStablecoin.getMinterPDA(
+        mint,
+        minter,
+        this.program.programId
+      ),
+      recipient,
+      mint,
+    } as any);
+  }
+
+  /** Approve `amount` more issuance for an allowance-mode minter. Minter controller or master. */
+  increaseMinterAllowance(
+    controller: PublicKey,
+    minter: PublicKey,
+    amount: number | string | bigint
+  ) {
+    return this.adjustMinterAllowance("increase", controller, minter, amount);
+  }
+
+  /** Withdraw up to `amount` of unused allowance (clamped at zero). Minter controller or master. */
+  decreaseMinterAllowance(
+    controller: PublicKey,
+    minter: PublicKey,
+    amount: number | string | bigint
+  ) {
+    return this.adjustMinterAllowance("decrease", controller, minter, amount);
+  }
+
+  private adjustMinterAllowance(
+    direction: "increase" | "decrease",
+    controller: PublicKey,
+    minter: PublicKey,
+    amount: number | string | bigint
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    const amountBn = new BN(amount.toString());
+    const method =
+      direction === "increase"
+        ? this.program.methods.increaseMinterAllowance(amountBn)
+        : this.program.methods.decreaseMinterAllowance(amountBn);
+    return method.accounts({
+      controller,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
+      minter,
+      minterConfig: SolanaStablecoin.getMinterPDA(
+        mint,
+        minter,
+        this.program.programId
+      ),
+      mint,
+    } as any);
+  }
+
   /** @deprecated Use addMinter for new minters, updateMinterQuota for existing. */
   async updateMinter(
     authority: PublicKey,
@@ -558,6 +1019,8 @@ export class SolanaStablecoin {
       pauser?: PublicKey | null;
       blacklister?: PublicKey | null;
       seizer?: PublicKey | null;
+      minterController?: PublicKey | null;
+      allowlister?: PublicKey | null;
     }
   ) {
     if (!this.mintAddress) throw new Error("Mint not set");
@@ -572,7 +1035,9 @@ export class SolanaStablecoin {
         roles.burner || null,
         roles.pauser || null,
         roles.blacklister || null,
-        roles.seizer || null
+        roles.seizer || null,
+        roles.minterController || null,
+        roles.allowlister || null
       )
       .accounts({
         admin: authority,
@@ -582,6 +1047,191 @@ export class SolanaStablecoin {
       } as any);
   }
 
+  /**
+   * Upgrade this mint's config and roles PDAs to the current program layout
+   * (e.g. after a program upgrade). Master authority only; safe to re-run.
+   */
+  migrateAccounts(authority: PublicKey) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.migrateAccounts().accounts({
+      admin: authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      roles: SolanaStablecoin.getRoleAccountPDA(mint, this.program.programId),
+      mint,
+      systemProgram: SystemProgram.programId,
+    } as any);
+  }
+
+  /**
+   * Upgrade a first-deployment minter's config PDA: it moves to the default
+   * 24h rolling window (usage from the legacy day carried over) in
+   * rolling-window mode with zero allowance. Run after `migrateAccounts`.
+   * Master authority only; safe to re-run.
+   */
+  migrateMinterConfig(authority: PublicKey, minter: PublicKey) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods.migrateMinterConfig().accounts({
+      admin: authority,
+      config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+      minter,
+      minterConfig: SolanaStablecoin.getMinterPDA(
+        mint,
+        minter,
+        this.program.programId
+      ),
+      mint,
+      systemProgram: SystemProgram.programId,
+    } as any);
+  }
+
+  /** Set (or clear with null) the global supply cap enforced on mint. Master authority only. */
+  setMaxSupply(
+    authority: PublicKey,
+    maxSupply: number | string | bigint | null
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods
+      .setMaxSupply(maxSupply === null ? null : new BN(maxSupply.toString()))
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        mint,
+      } as any);
+  }
+
+  /**
+   * Set (or clear with null) the amount above which the transfer hook requires
+   * a travel-rule memo (see createTravelRuleMemoInstruction). Master authority only.
+   */
+  setTravelRuleThreshold(
+    authority: PublicKey,
+    threshold: number | string | bigint | null
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods
+      .setTravelRuleThreshold(
+        threshold === null ? null : new BN(threshold.toString())
+      )
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        mint,
+      } as any);
+  }
+
+  /**
+   * Pin (or clear with null) the Switchboard queue and 32-byte feed hash that
+   * mintAtOraclePrice accepts quotes from. Master authority only.
+   */
+  setOracleFeed(
+    authority: PublicKey,
+    feed: { queue: PublicKey; feedId: Uint8Array | number[] } | null
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    if (feed && feed.feedId.length !== 32) {
+      throw new Error("Feed ID must be 32 bytes");
+    }
+    const mint = this.mintAddress;
+    return this.program.methods
+      .setOracleFeed(
+        feed === null
+          ? null
+          : { queue: feed.queue, feedId: Array.from(feed.feedId) }
+      )
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        mint,
+      } as any);
+  }
+
+  /**
+   * Set the outbound caps for transfer limit `tier` (base units; 0 = no cap).
+   * Master authority only.
+   */
+  setTransferLimitTier(
+    authority: PublicKey,
+    tier: number,
+    dailyLimit: number | string | bigint,
+    perTransferLimit: number | string | bigint
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods
+      .setTransferLimitTier(
+        tier,
+        new BN(dailyLimit.toString()),
+        new BN(perTransferLimit.toString())
+      )
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        mint,
+      } as any);
+  }
+
+  /**
+   * Step 1 of a two-step handover: master authority proposes `newAuthority` for `role`.
+   * Nothing changes until the proposed key calls acceptAuthority.
+   */
+  proposeAuthority(
+    authority: PublicKey,
+    role: AuthorityRole,
+    newAuthority: PublicKey
+  ) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods
+      .proposeAuthority({ [role]: {} } as any, newAuthority)
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        roles: SolanaStablecoin.getRoleAccountPDA(
+          mint,
+          this.program.programId
+        ),
+        mint,
+      } as any);
+  }
+
+  /** Step 2: the proposed key signs to take over `role`. */
+  acceptAuthority(newAuthority: PublicKey, role: AuthorityRole) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods
+      .acceptAuthority({ [role]: {} } as any)
+      .accounts({
+        newAuthority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        roles: SolanaStablecoin.getRoleAccountPDA(
+          mint,
+          this.program.programId
+        ),
+        mint,
+      } as any);
+  }
+
+  /** Master authority withdraws a pending proposal for `role`. */
+  cancelAuthorityTransfer(authority: PublicKey, role: AuthorityRole) {
+    if (!this.mintAddress) throw new Error("Mint not set");
+    const mint = this.mintAddress;
+    return this.program.methods
+      .cancelAuthorityTransfer({ [role]: {} } as any)
+      .accounts({
+        admin: authority,
+        config: SolanaStablecoin.getConfigPDA(mint, this.program.programId),
+        roles: SolanaStablecoin.getRoleAccountPDA(
+          mint,
+          this.program.programId
+        ),
+        mint,
+      } as any);
+  }
+
   // --- View methods (req: getTotalSupply, getConfig, getRoles) ---
 
   /** Returns total supply of the stablecoin mint. Requires mintAddress to be set. */
@@ -609,15 +1259,49 @@ export class SolanaStablecoin {
       symbol: raw.symbol ?? "",
       uri: raw.uri ?? "",
       decimals: raw.decimals,
-      isPaused: raw.isPaused,
+      isPaused: raw.pauseFlags !== 0,
+      pauseFlags: raw.pauseFlags,
+      pauseReason: Object.keys(raw.pauseReason)[0] as PauseReasonCode,
+      pausedAt: raw.pausedAt.toNumber(),
       enablePermanentDelegate: raw.enablePermanentDelegate,
       enableTransferHook: raw.enableTransferHook,
       defaultAccountFrozen: raw.defaultAccountFrozen ?? false,
       enableConfidentialTransfers: raw.enableConfidentialTransfers,
       enableAllowlist: raw.enableAllowlist ?? false,
+      pendingAuthority: raw.pendingAuthority ?? null,
+      maxSupply:
+        raw.maxSupply != null ? BigInt(raw.maxSupply.toString()) : null,
+      transferLimitTiers: (raw.transferLimitTiers ?? []).map((tier: any) => ({
+        dailyLimit: BigInt(tier.dailyLimit.toString()),
+        perTransferLimit: BigInt(tier.perTransferLimit.toString()),
+      })),
+      travelRuleThreshold:
+        raw.travelRuleThreshold != null
+          ? BigInt(raw.travelRuleThreshold.toString())
+          : null,
+      enableConfidentialMintBurn: raw.enableConfidentialMintBurn ?? false,
+      confidentialSupply: BigInt((raw.confidentialSupply ?? 0).toString()),
+      oracleFeed:
+        raw.oracleFeed != null
+          ? {
+              queue: raw.oracleFeed.queue,
+              feedId: Uint8Array.from(raw.oracleFeed.feedId),
+            }
+          : null,
     };
   }
 
+  /**
+   * Remaining issuance allowed by the global supply cap (maxSupply - current supply, floored at 0).
+   * Returns null when the stablecoin is uncapped.
+   */
+  async getSupplyHeadroom(): Promise<bigint | null> {
+    const config = await this.getConfig();
+    if (config.maxSupply === null) return null;
+    const supply = await this.getTotalSupply();
+    return config.maxSupply > supply ? config.maxSupply - supply : BigInt(0);
+  }
+
   /** Fetches on-chain role account (burner, pauser, blacklister, seizer). */
   async getRoles(): Promise<RoleAccountData> {
     if (!this.mintAddress) throw new Error("Mint not set");
@@ -632,6 +1316,10 @@ export class SolanaStablecoin {
       pauser: raw.pauser,
       blacklister: raw.blacklister,
       seizer: raw.seizer,
+      pendingBurner: raw.pendingBurner ?? null,
+      pendingPauser: raw.pendingPauser ?? null,
+      pendingBlacklister: raw.pendingBlacklister ?? null,
+      pendingSeizer: raw.pendingSeizer ?? null,
     };
   }
 
@@ -777,8 +1465,7 @@ export class SolanaStablecoin {
         instance
       );
       const hookInit = await compliance.initializeTransferHookExtraAccounts(
-        authority,
-        config.enableAllowlist ?? false
+        authority
       );
       await hookInit.rpc();
     }
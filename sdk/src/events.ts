@@ -0,0 +1,64 @@
+import { utils } from "@coral-xyz/anchor";
+import type { EventCoder } from "@coral-xyz/anchor";
+import type {
+  Connection,
+  PublicKey,
+  VersionedTransactionResponse,
+} from "@solana/web3.js";
+
+/**
+ * First 8 bytes of the self-invocation `emit_cpi!` uses to carry an event
+ * (Anchor's `EVENT_IX_TAG`, little-endian). The event discriminator and its
+ * Borsh data follow.
+ */
+export const EVENT_IX_TAG_LE = Buffer.from("e445a52e51cb9a1d", "hex");
+
+/**
+ * A typed program event, e.g. `{ name: "minted", data: { mint, amount, ... } }`.
+ * A `Program`'s coder yields camelCase names and fields; a `BorshCoder` built
+ * from the raw IDL yields the names as written in the program.
+ */
+export interface DecodedEvent {
+  name: string;
+  data: Record<string, any>;
+}
+
+/**
+ * Decode every event `programId` emitted with `emit_cpi!` in `tx`, in
+ * execution order. Events travel as inner instructions, so the transaction
+ * must be fetched with `maxSupportedTransactionVersion: 0`.
+ */
+export function decodeCpiEvents(
+  coder: { events: EventCoder },
+  programId: PublicKey,
+  tx: VersionedTransactionResponse
+): DecodedEvent[] {
+  const keys = tx.transaction.message.getAccountKeys({
+    accountKeysFromLookups: tx.meta?.loadedAddresses,
+  });
+  const events: DecodedEvent[] = [];
+  for (const inner of tx.meta?.innerInstructions ?? []) {
+    for (const ix of inner.instructions) {
+      if (!keys.get(ix.programIdIndex)?.equals(programId)) continue;
+      const data = Buffer.from(utils.bytes.bs58.decode(ix.data));
+      if (!data.subarray(0, 8).equals(EVENT_IX_TAG_LE)) continue;
+      const event = coder.events.decode(data.subarray(8).toString("base64"));
+      if (event) events.push(event as DecodedEvent);
+    }
+  }
+  return events;
+}
+
+/** Fetch `signature` (confirmed) and decode the program's CPI events from it. */
+export async function fetchCpiEvents(
+  connection: Connection,
+  program: { coder: { events: EventCoder }; programId: PublicKey },
+  signature: string
+): Promise<DecodedEvent[]> {
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  if (!tx) throw new Error(`Transaction ${signature} not found`);
+  return decodeCpiEvents(program.coder, program.programId, tx);
+}
@@ -22,5 +22,6 @@ export async function buildMintInstructions(
 }
 export * from "./compliance";
 export * from "./confidential";
+export * from "./events";
 export * from "./presets";
 export * from "./oracle";
@@ -1,11 +1,11 @@
-import type {
-  Connection,
-  PublicKey,
-  TransactionInstruction,
-} from "@solana/web3.js";
-import { Transaction } from "@solana/web3.js";
+import type { Connection, TransactionInstruction } from "@solana/web3.js";
+import { PublicKey, Transaction } from "@solana/web3.js";
 import type { Program } from "@coral-xyz/anchor";
 import type { Oracle } from "../../target/types/oracle";
+import * as oracleIdl from "../../target/idl/oracle.json";
+
+/** Oracle program the stablecoin's `mint_at_oracle_price` CPIs into (its IDL address / `declare_id`). */
+export const ORACLE_PROGRAM_ID = new PublicKey(oracleIdl.address);
 
 export interface OracleComputeAmountParams {
   connection: Connection;
@@ -20,6 +20,11 @@ export interface OracleComputeAmountParams {
   pegAmount: bigint;
   /** Stablecoin token decimals (0–18). */
   tokenDecimals: number;
+  /**
+   * 32-byte Switchboard feed hash to price with (the quote may carry several
+   * feeds). Omit to price with the quote's first feed.
+   */
+  feedId?: Uint8Array | number[];
   /** Optional additional instructions (e.g. Switchboard update + Ed25519 verify) that must run before oracle ix. */
   preInstructions?: TransactionInstruction[];
 }
@@ -35,11 +40,21 @@ export async function computeMintAmountFromOracle(
     instructionsSysvar,
     pegAmount,
     tokenDecimals,
+    feedId,
     preInstructions = [],
   } = params;
+  if (feedId != null && feedId.length !== 32)
+    throw new Error("Feed ID must be 32 bytes");
 
-  const ix = await program.methods
-    .computeMintAmount(pegAmount, tokenDecimals)
+  const method =
+    feedId == null
+      ? program.methods.computeMintAmount(pegAmount, tokenDecimals)
+      : program.methods.computeMintAmountForFeed(
+          pegAmount,
+          tokenDecimals,
+          Array.from(feedId)
+        );
+  const ix = await method
     .accounts({
       queue,
       slotHashes,
@@ -30,6 +30,8 @@ export const SSS_3_PRESET: Omit<
   defaultAccountFrozen: false,
   enableConfidentialTransfers: true,
   enableAllowlist: true,
+  // Regulated: each confidential account is approved after KYC (approveAccount).
+  autoApproveConfidentialAccounts: false,
 };
 
 export const Presets = {
@@ -8,6 +8,8 @@ import { registerUnitErrorsSuite } from "./suites/unit-errors";
 import { registerUnitSuccessSuite } from "./suites/unit-success";
 import { registerSdkUnitSuite } from "./suites/sdk-unit";
 import { registerOracleSuite } from "./suites/oracle";
+import { registerEventsSuite } from "./suites/events";
+import { registerMigrationSuite } from "./suites/migration";
 
 describe("solana-stablecoin-standard", () => {
   const ctx = createTestContext();
@@ -42,4 +44,6 @@ describe("solana-stablecoin-standard", () => {
   registerUnitSuccessSuite(ctx);
   registerSdkUnitSuite(ctx);
   registerOracleSuite(ctx);
+  registerEventsSuite(ctx);
+  registerMigrationSuite(ctx);
 });
@@ -0,0 +1,373 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountInstruction,
+} from "@solana/spl-token";
+import {
+  SolanaStablecoin,
+  SSSComplianceModule,
+  SSS_2_PRESET,
+  PauseFlags,
+  fetchCpiEvents,
+  type DecodedEvent,
+} from "../../sdk/src";
+import type { TestContext } from "../context";
+
+/**
+ * One test per event family: each sends the instruction, fetches the confirmed
+ * transaction and decodes the `emit_cpi!` inner instruction with the program's
+ * coder (so event and field names are camelCase).
+ */
+export function registerEventsSuite(ctx: TestContext): void {
+  const {
+    provider,
+    connection,
+    authority,
+    stablecoinProgram,
+    transferHookProgram,
+    user1,
+  } = ctx;
+
+  describe("Events: CPI events decoded from transactions", () => {
+    const SYMBOL = "EVTS";
+    let sdk: SolanaStablecoin;
+    let compliance: SSSComplianceModule;
+    let mint: anchor.web3.PublicKey;
+
+    /** Send a builder at `confirmed` and return the events it emitted. */
+    async function sendAndDecode(builder: {
+      rpc: (opts?: anchor.web3.ConfirmOptions) => Promise<string>;
+    }): Promise<DecodedEvent[]> {
+      const sig = await builder.rpc({ commitment: "confirmed" });
+      return fetchCpiEvents(connection, stablecoinProgram, sig);
+    }
+
+    function only(events: DecodedEvent[], name: string): DecodedEvent {
+      const matching = events.filter((e) => e.name === name);
+      expect(
+        matching,
+        `${name} in ${events.map((e) => e.name)}`
+      ).to.have.length(1);
+      return matching[0];
+    }
+
+    it("initialize emits StablecoinInitialized", async () => {
+      sdk = new SolanaStablecoin(
+        stablecoinProgram,
+        undefined,
+        transferHookProgram
+      );
+      const events = await sendAndDecode(
+        await sdk.initialize(
+          authority.publicKey,
+          {
+            name: "Events USD",
+            symbol: SYMBOL,
+            uri: "https://example.com/evts",
+            decimals: 6,
+            ...SSS_2_PRESET,
+            enableAllowlist: true,
+          },
+          transferHookProgram.programId
+        )
+      );
+      mint = SolanaStablecoin.getMintPDA(SYMBOL, stablecoinProgram.programId);
+      compliance = new SSSComplianceModule(sdk);
+
+      const { data } = only(events, "stablecoinInitialized");
+      expect(data.mint.equals(mint)).to.be.true;
+      expect(data.authority.equals(authority.publicKey)).to.be.true;
+      expect(data.decimals).to.equal(6);
+      expect(data.enableTransferHook).to.be.true;
+      expect(data.enableAllowlist).to.be.true;
+      expect(data.enableConfidentialTransfers).to.be.false;
+    });
+
+    it("update_metadata emits MetadataUpdated", async () => {
+      const uri = "https://example.com/evts-v2.json";
+      const events = await sendAndDecode(
+        sdk.updateMetadata(authority.publicKey, { uri })
+      );
+      const { data } = only(events, "metadataUpdated");
+      expect(data.name).to.equal("Events USD");
+      expect(data.uri).to.equal(uri);
+    });
+
+    it("minter admin emits MinterUpdated, MinterAllowanceUpdated and MinterRemoved", async () => {
+      const minter = anchor.web3.Keypair.generate().publicKey;
+      let { data } = only(
+        await sendAndDecode(
+          await sdk.addMinter(
+            authority.publicKey,
+            minter,
+            true,
+            0,
+            null,
+            "allowance"
+          )
+        ),
+        "minterUpdated"
+      );
+      expect(data.minter.equals(minter)).to.be.true;
+      expect(data.isActive).to.be.true;
+      expect(data.quotaMode).to.deep.equal({ allowance: {} });
+
+      ({ data } = only(
+        await sendAndDecode(
+          sdk.increaseMinterAllowance(authority.publicKey, minter, 500)
+        ),
+        "minterAllowanceUpdated"
+      ));
+      expect(data.previousAllowance.toNumber()).to.equal(0);
+      expect(data.allowance.toNumber()).to.equal(500);
+
+      ({ data } = only(
+        await sendAndDecode(sdk.removeMinter(authority.publicKey, minter)),
+        "minterRemoved"
+      ));
+      expect(data.minter.equals(minter)).to.be.true;
+      expect(data.recipient.equals(authority.publicKey)).to.be.true;
+    });
+
+    it("mint and burn emit Minted and Burned with supply before/after", async () => {
+      const ata = getAssociatedTokenAddressSync(
+        mint,
+        authority.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            authority.publicKey,
+            ata,
+            authority.publicKey,
+            mint,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+      await sdk
+        .addMinter(authority.publicKey, authority.publicKey, true, 1_000_000)
+        .then((tx) => tx.rpc());
+
+      let { data } = only(
+        await sendAndDecode(
+          await sdk.mint(authority.publicKey, authority.publicKey, 1_000)
+        ),
+        "minted"
+      );
+      expect(data.to.equals(ata)).to.be.true;
+      expect(data.amount.toNumber()).to.equal(1_000);
+      expect(data.supplyBefore.toNumber()).to.equal(0);
+      expect(data.supplyAfter.toNumber()).to.equal(1_000);
+
+      ({ data } = only(
+        await sendAndDecode(
+          await sdk.burn(authority.publicKey, authority.publicKey, 400)
+        ),
+        "burned"
+      ));
+      expect(data.from.equals(ata)).to.be.true;
+      expect(data.amount.toNumber()).to.equal(400);
+      expect(data.supplyBefore.toNumber()).to.equal(1_000);
+      expect(data.supplyAfter.toNumber()).to.equal(600);
+    });
+
+    it("pause and unpause emit Paused and Unpaused", async () => {
+      let { data } = only(
+        await sendAndDecode(
+          await sdk.pause(authority.publicKey, PauseFlags.mint, "incident")
+        ),
+        "paused"
+      );
+      expect(data.previousFlags).to.equal(0);
+      expect(data.pauseFlags).to.equal(PauseFlags.mint);
+      expect(data.reason).to.deep.equal({ incident: {} });
+
+      ({ data } = only(
+        await sendAndDecode(await sdk.unpause(authority.publicKey)),
+        "unpaused"
+      ));
+      expect(data.previousFlags).to.equal(PauseFlags.mint);
+      expect(data.pauseFlags).to.equal(0);
+    });
+
+    it("authority handover emits AuthorityTransferProposed and AuthorityTransferCancelled", async () => {
+      let { data } = only(
+        await sendAndDecode(
+          sdk.proposeAuthority(authority.publicKey, "pauser", user1.publicKey)
+        ),
+        "authorityTransferProposed"
+      );
+      expect(data.role).to.deep.equal({ pauser: {} });
+      expect(data.proposedAuthority.equals(user1.publicKey)).to.be.true;
+
+      ({ data } = only(
+        await sendAndDecode(
+          sdk.cancelAuthorityTransfer(authority.publicKey, "pauser")
+        ),
+        "authorityTransferCancelled"
+      ));
+      expect(data.role).to.deep.equal({ pauser: {} });
+      expect(data.cancelledAuthority.equals(user1.publicKey)).to.be.true;
+    });
+
+    it("update_roles emits RolesUpdated with before and after", async () => {
+      const { data } = only(
+        await sendAndDecode(
+          await sdk.updateRoles(authority.publicKey, {
+            allowlister: user1.publicKey,
+          })
+        ),
+        "rolesUpdated"
+      );
+      expect(data.previous.allowlister.equals(authority.publicKey)).to.be.true;
+      expect(data.current.allowlister.equals(user1.publicKey)).to.be.true;
+      expect(data.current.pauser.equals(data.previous.pauser)).to.be.true;
+    });
+
+    it("config setters emit MaxSupplyUpdated, TravelRuleThresholdUpdated, TransferLimitTierUpdated and OracleFeedUpdated", async () => {
+      let { data } = only(
+        await sendAndDecode(sdk.setMaxSupply(authority.publicKey, 10_000)),
+        "maxSupplyUpdated"
+      );
+      expect(data.previousMaxSupply).to.be.null;
+      expect(data.maxSupply.toNumber()).to.equal(10_000);
+
+      ({ data } = only(
+        await sendAndDecode(
+          sdk.setTravelRuleThreshold(authority.publicKey, 3_000)
+        ),
+        "travelRuleThresholdUpdated"
+      ));
+      expect(data.previousThreshold).to.be.null;
+      expect(data.threshold.toNumber()).to.equal(3_000);
+
+      ({ data } = only(
+        await sendAndDecode(
+          sdk.setTransferLimitTier(authority.publicKey, 1, 5_000, 1_000)
+        ),
+        "transferLimitTierUpdated"
+      ));
+      expect(data.tier).to.equal(1);
+      expect(data.limits.dailyLimit.toNumber()).to.equal(5_000);
+      expect(data.limits.perTransferLimit.toNumber()).to.equal(1_000);
+
+      const queue = anchor.web3.Keypair.generate().publicKey;
+      const feedId = new Uint8Array(32).fill(9);
+      ({ data } = only(
+        await sendAndDecode(
+          sdk.setOracleFeed(authority.publicKey, { queue, feedId })
+        ),
+        "oracleFeedUpdated"
+      ));
+      expect(data.previousFeed).to.be.null;
+      expect(data.feed.queue.equals(queue)).to.be.true;
+      expect(data.feed.feedId).to.deep.equal(Array.from(feedId));
+    });
+
+    it("freeze, thaw and default state emit AccountFrozen, AccountThawed and DefaultAccountStateUpdated", async () => {
+      const ata = getAssociatedTokenAddressSync(
+        mint,
+        authority.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      let { data } = only(
+        await sendAndDecode(
+          await sdk.freezeAccount(authority.publicKey, authority.publicKey)
+        ),
+        "accountFrozen"
+      );
+      expect(data.tokenAccount.equals(ata)).to.be.true;
+
+      ({ data } = only(
+        await sendAndDecode(
+          await sdk.thawAccount(authority.publicKey, authority.publicKey)
+        ),
+        "accountThawed"
+      ));
+      expect(data.tokenAccount.equals(ata)).to.be.true;
+
+      ({ data } = only(
+        await sendAndDecode(
+          sdk.setDefaultAccountState(authority.publicKey, true)
+        ),
+        "defaultAccountStateUpdated"
+      ));
+      expect(data.wasFrozen).to.be.false;
+      expect(data.isFrozen).to.be.true;
+      await sdk
+        .setDefaultAccountState(authority.publicKey, false)
+        .rpc({ commitment: "confirmed" });
+    });
+
+    it("blacklist emits Blacklisted and BlacklistEntryClosed", async () => {
+      const wallet = anchor.web3.Keypair.generate().publicKey;
+      let { data } = only(
+        await sendAndDecode(
+          await compliance.addToBlacklist(
+            authority.publicKey,
+            wallet,
+            undefined,
+            "sanctions",
+            "CASE-EVTS"
+          )
+        ),
+        "blacklisted"
+      );
+      expect(data.account.equals(wallet)).to.be.true;
+      expect(data.wasBlacklisted).to.be.false;
+      expect(data.isBlacklisted).to.be.true;
+      expect(data.reason).to.deep.equal({ sanctions: {} });
+
+      await compliance
+        .removeFromBlacklist(authority.publicKey, wallet)
+        .then((tx) => tx.rpc({ commitment: "confirmed" }));
+      ({ data } = only(
+        await sendAndDecode(
+          compliance.closeBlacklistEntry(authority.publicKey, wallet)
+        ),
+        "blacklistEntryClosed"
+      ));
+      expect(data.account.equals(wallet)).to.be.true;
+      expect(data.wasBlacklisted).to.be.false;
+    });
+
+    it("allowlist emits AllowlistChanged and AllowlistEnabledUpdated", async () => {
+      const wallet = anchor.web3.Keypair.generate().publicKey;
+      let { data } = only(
+        await sendAndDecode(
+          await sdk.addToAllowlist(authority.publicKey, wallet)
+        ),
+        "allowlistChanged"
+      );
+      expect(data.wallet.equals(wallet)).to.be.true;
+      expect(data.wasAllowed).to.be.false;
+      expect(data.isAllowed).to.be.true;
+      expect(data.tier).to.equal(0);
+
+      ({ data } = only(
+        await sendAndDecode(
+          sdk.setAllowlistEnabled(authority.publicKey, false)
+        ),
+        "allowlistEnabledUpdated"
+      ));
+      expect(data.wasEnabled).to.be.true;
+      expect(data.enabled).to.be.false;
+    });
+
+    it("migrate_accounts emits AccountsMigrated (no-op on current layouts)", async () => {
+      const { data } = only(
+        await sendAndDecode(sdk.migrateAccounts(authority.publicKey)),
+        "accountsMigrated"
+      );
+      expect(data.mint.equals(mint)).to.be.true;
+      expect(data.configMigrated).to.be.false;
+      expect(data.rolesMigrated).to.be.false;
+    });
+  });
+}
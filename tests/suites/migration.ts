@@ -0,0 +1,149 @@
+import * as anchor from "@coral-xyz/anchor";
+import { expect } from "chai";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createAssociatedTokenAccountInstruction,
+} from "@solana/spl-token";
+import { SolanaStablecoin, PauseFlags } from "../../sdk/src";
+import type { TestContext } from "../context";
+
+// Deployment preloaded from tests/fixtures/legacy-lgcy-*.json in the account layouts the
+// program first shipped with: a 428-byte config, a RoleAccountV0 and a 74-byte minter whose
+// quota of 1_000_000 was used up long ago. tests/fixtures/legacy-lgps-*.json is a second
+// 428-byte deployment saved while paused. The master and minter are deterministic keys.
+const LEGACY_MASTER = anchor.web3.Keypair.fromSeed(new Uint8Array(32).fill(21));
+const LEGACY_MINTER = anchor.web3.Keypair.fromSeed(new Uint8Array(32).fill(22));
+const LEGACY_QUOTA = 1_000_000;
+
+export function registerMigrationSuite(ctx: TestContext): void {
+  const { provider, connection, stablecoinProgram } = ctx;
+
+  describe("Migration: legacy account layouts", () => {
+    let sdk: SolanaStablecoin;
+
+    before(async () => {
+      for (const key of [LEGACY_MASTER.publicKey, LEGACY_MINTER.publicKey]) {
+        const sig = await connection.requestAirdrop(
+          key,
+          2 * anchor.web3.LAMPORTS_PER_SOL
+        );
+        await connection.confirmTransaction(
+          { signature: sig, ...(await connection.getLatestBlockhash()) },
+          "confirmed"
+        );
+      }
+      sdk = new SolanaStablecoin(
+        stablecoinProgram,
+        SolanaStablecoin.getMintPDA("LGCY", stablecoinProgram.programId)
+      );
+      await sdk
+        .migrateAccounts(LEGACY_MASTER.publicKey)
+        .signers([LEGACY_MASTER])
+        .rpc();
+      const ata = getAssociatedTokenAddressSync(
+        sdk.mintAddress!,
+        LEGACY_MINTER.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            provider.wallet.publicKey,
+            ata,
+            LEGACY_MINTER.publicKey,
+            sdk.mintAddress!,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+    });
+
+    it("migrate_minter_config moves a legacy minter onto the default rolling window, then it mints", async () => {
+      const minterPda = SolanaStablecoin.getMinterPDA(
+        sdk.mintAddress!,
+        LEGACY_MINTER.publicKey,
+        stablecoinProgram.programId
+      );
+      let err: unknown;
+      try {
+        await sdk
+          .mint(LEGACY_MINTER.publicKey, LEGACY_MINTER.publicKey, 1)
+          .then((tx) => tx.signers([LEGACY_MINTER]).rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "AccountDidNotDeserialize"
+      );
+
+      await sdk
+        .migrateMinterConfig(LEGACY_MASTER.publicKey, LEGACY_MINTER.publicKey)
+        .signers([LEGACY_MASTER])
+        .rpc();
+      const info = await connection.getAccountInfo(minterPda);
+      expect(info!.data.length).to.equal(
+        stablecoinProgram.account.minterConfig.size
+      );
+      const migrated = await stablecoinProgram.account.minterConfig.fetch(
+        minterPda
+      );
+      expect(migrated.windowSeconds).to.equal(86_400);
+      expect(migrated.quotaMode).to.deep.equal({ rollingWindow: {} });
+      expect(migrated.dailyMintQuota.toNumber()).to.equal(LEGACY_QUOTA);
+      expect(migrated.totalMinted.toNumber()).to.equal(LEGACY_QUOTA);
+      // The legacy day reset long ago, so none of its usage is carried over.
+      expect(migrated.dailyMinted.toNumber()).to.equal(0);
+
+      await sdk
+        .mint(LEGACY_MINTER.publicKey, LEGACY_MINTER.publicKey, LEGACY_QUOTA)
+        .then((tx) => tx.signers([LEGACY_MINTER]).rpc());
+      err = undefined;
+      try {
+        await sdk
+          .mint(LEGACY_MINTER.publicKey, LEGACY_MINTER.publicKey, 1)
+          .then((tx) => tx.signers([LEGACY_MINTER]).rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include("QuotaExceeded");
+
+      // Already current: re-running is a no-op.
+      await sdk
+        .migrateMinterConfig(LEGACY_MASTER.publicKey, LEGACY_MINTER.publicKey)
+        .signers([LEGACY_MASTER])
+        .rpc();
+      const after = await stablecoinProgram.account.minterConfig.fetch(
+        minterPda
+      );
+      expect(after.totalMinted.toNumber()).to.equal(2 * LEGACY_QUOTA);
+    });
+
+    it("migrate_accounts turns a paused 428-byte config into mint + burn paused", async () => {
+      const pausedSdk = new SolanaStablecoin(
+        stablecoinProgram,
+        SolanaStablecoin.getMintPDA("LGPS", stablecoinProgram.programId)
+      );
+      const configPda = SolanaStablecoin.getConfigPDA(
+        pausedSdk.mintAddress!,
+        stablecoinProgram.programId
+      );
+      const configLen = async () =>
+        (await connection.getAccountInfo(configPda))!.data.length;
+      expect(await configLen()).to.equal(428);
+      await pausedSdk
+        .migrateAccounts(LEGACY_MASTER.publicKey)
+        .signers([LEGACY_MASTER])
+        .rpc();
+      expect(await configLen()).to.equal(
+        stablecoinProgram.account.stablecoinConfig.size
+      );
+      const config = await pausedSdk.getConfig();
+      expect(config.pauseFlags).to.equal(PauseFlags.mint | PauseFlags.burn);
+      expect(config.pauseReason).to.equal("unspecified");
+    });
+  });
+}
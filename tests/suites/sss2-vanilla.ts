@@ -6,11 +6,18 @@ import {
   createAssociatedTokenAccountInstruction,
   getAccount,
   createTransferCheckedWithTransferHookInstruction,
+  createExecuteInstruction,
+  getExtraAccountMetaAddress,
+  createApproveInstruction,
+  createRevokeInstruction,
 } from "@solana/spl-token";
 import {
   SolanaStablecoin,
   SSSComplianceModule,
   SSS_2_PRESET,
+  toCaseReference,
+  createTravelRuleMemoInstruction,
+  PauseFlags,
 } from "../../sdk/src";
 import type { TestContext } from "../context";
 
@@ -57,6 +64,19 @@ export function registerSSS2VanillaSuite(ctx: TestContext): void {
       expect(configData.enableTransferHook).to.be.true;
     });
 
+    it("Rejects ExtraAccountMetaList init by a non-authority", async () => {
+      let err: unknown;
+      try {
+        await complianceSdk
+          .initializeTransferHookExtraAccounts(user2.publicKey)
+          .then((tx) => tx.signers([user2]).rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include("Unauthorized");
+    });
+
     it("Initializes the Transfer Hook ExtraAccountMetaList", async () => {
       const initHook = await complianceSdk.initializeTransferHookExtraAccounts(
         authority.publicKey
@@ -79,6 +99,21 @@ export function registerSSS2VanillaSuite(ctx: TestContext): void {
       expect(accInfo).to.not.be.null;
     });
 
+    it("Refuses to re-initialize the ExtraAccountMetaList", async () => {
+      let err: unknown;
+      try {
+        await complianceSdk
+          .initializeTransferHookExtraAccounts(authority.publicKey)
+          .then((tx) => tx.rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect(String((err as { message?: string }).message)).to.match(
+        /already in use|0x0/
+      );
+    });
+
     it("Mints tokens to user1", async () => {
       const mintPda = SolanaStablecoin.getMintPDA(
         "SUSD",
@@ -192,9 +227,143 @@ export function registerSSS2VanillaSuite(ctx: TestContext): void {
       expect(Number(accInfoAfter2.amount)).to.equal(500000);
     });
 
+    it("Rejects a transfer hook call with a spoofed blacklist account", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      const user1Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        user1Ata,
+        mintPda,
+        user2Ata,
+        user1.publicKey,
+        BigInt(1),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      // [source, mint, dest, owner, ...extras, hook program, validation list]
+      const extras = transferIx.keys.slice(4, transferIx.keys.length - 2);
+      const spoofed = SolanaStablecoin.getBlacklistEntryPDA(
+        mintPda,
+        anchor.web3.Keypair.generate().publicKey,
+        stablecoinProgram.programId
+      );
+      // extras: 0 stablecoin program, 1 source blacklist, 2 dest blacklist, 3 config
+      extras[2] = { ...extras[2], pubkey: spoofed };
+
+      const executeIx = createExecuteInstruction(
+        transferHookProgram.programId,
+        user1Ata,
+        mintPda,
+        user2Ata,
+        user1.publicKey,
+        getExtraAccountMetaAddress(mintPda, transferHookProgram.programId),
+        BigInt(1)
+      );
+      const execute = async (keys: anchor.web3.AccountMeta[]) => {
+        const ix = new anchor.web3.TransactionInstruction({
+          ...executeIx,
+          keys: [...executeIx.keys, ...keys],
+        });
+        try {
+          await provider.sendAndConfirm(new anchor.web3.Transaction().add(ix));
+        } catch (e: any) {
+          return e?.message ?? e?.toString?.() ?? String(e);
+        }
+        return "";
+      };
+      expect(await execute(extras)).to.match(
+        /InvalidBlacklistAccount|not the PDA for this mint and wallet|0x1776/i
+      );
+      // Dropping the config PDA no longer matches the stored list's account count.
+      expect(await execute(transferIx.keys.slice(4, 7))).to.match(
+        /InvalidExtraAccounts|do not match the mint's extra account meta list|0x1774/i
+      );
+    });
+
+    it("Rejects a transfer hook call made outside a Token-2022 transfer", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      const user1Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        user1Ata,
+        mintPda,
+        user2Ata,
+        user1.publicKey,
+        BigInt(1),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const extras = transferIx.keys.slice(4, transferIx.keys.length - 2);
+      const execute = async (owner: anchor.web3.PublicKey) => {
+        const ix = createExecuteInstruction(
+          transferHookProgram.programId,
+          user1Ata,
+          mintPda,
+          user2Ata,
+          owner,
+          getExtraAccountMetaAddress(mintPda, transferHookProgram.programId),
+          BigInt(1)
+        );
+        ix.keys.push(...extras);
+        try {
+          await provider.sendAndConfirm(new anchor.web3.Transaction().add(ix));
+        } catch (e: any) {
+          return e?.message ?? e?.toString?.() ?? String(e);
+        }
+        return "";
+      };
+      const notTransferring =
+        /NotTransferring|not invoked by a Token-2022 transfer|0x1779/i;
+      // Genuine accounts, but the source is not mid-transfer.
+      expect(await execute(user1.publicKey)).to.match(notTransferring);
+      // The seize bypass does not apply to direct calls either.
+      expect(
+        await execute(
+          SolanaStablecoin.getConfigPDA(mintPda, stablecoinProgram.programId)
+        )
+      ).to.match(notTransferring);
+    });
+
     it("Adds user2 to Blacklist", async () => {
       await complianceSdk
-        .addToBlacklist(authority.publicKey, user2.publicKey)
+        .addToBlacklist(
+          authority.publicKey,
+          user2.publicKey,
+          "CASE-42",
+          "sanctions"
+        )
         .then((tx) => tx.rpc());
       const mintPda = SolanaStablecoin.getMintPDA(
         "SUSD",
@@ -209,6 +378,10 @@ export function registerSSS2VanillaSuite(ctx: TestContext): void {
         blacklistPda
       );
       expect(entry.isBlacklisted).to.be.true;
+      expect(entry.reason).to.deep.equal({ sanctions: {} });
+      expect(entry.caseReference).to.deep.equal(toCaseReference("CASE-42"));
+      expect(entry.blacklistedBy.equals(authority.publicKey)).to.be.true;
+      expect(Number(entry.blacklistedAt)).to.be.greaterThan(0);
     });
 
     it("Fails to transfer to user2 because user2 is blacklisted", async () => {
@@ -319,6 +492,294 @@ export function registerSSS2VanillaSuite(ctx: TestContext): void {
         .then((tx) => tx.rpc());
     });
 
+    it("Blocks a blacklisted delegate from moving another wallet's tokens", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      const user1Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const authorityAta = getAssociatedTokenAddressSync(
+        mintPda,
+        authority.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      // user2 is still blacklisted; user1 (source) and authority (destination) are not.
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createApproveInstruction(
+            user1Ata,
+            user2.publicKey,
+            user1.publicKey,
+            BigInt(1_000),
+            [],
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [user1]
+      );
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        user1Ata,
+        mintPda,
+        authorityAta,
+        user2.publicKey,
+        BigInt(1_000),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      let errMsg = "";
+      try {
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(transferIx),
+          [user2]
+        );
+      } catch (e: any) {
+        errMsg = e?.message ?? e?.toString?.() ?? String(e);
+      }
+      expect(errMsg).to.match(/Blacklisted|Account is blacklisted|0x1770/i);
+
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createRevokeInstruction(
+            user1Ata,
+            user1.publicKey,
+            [],
+            TOKEN_2022_PROGRAM_ID
+          )
+        ),
+        [user1]
+      );
+    });
+
+    it("Requires a travel-rule memo on transfers above the threshold", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      const user1Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const authorityAta = getAssociatedTokenAddressSync(
+        mintPda,
+        authority.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await sss2Sdk.setTravelRuleThreshold(authority.publicKey, 1_000).rpc();
+      expect((await sss2Sdk.getConfig()).travelRuleThreshold).to.equal(
+        BigInt(1_000)
+      );
+
+      const transfer = async (
+        amount: number,
+        memo?: anchor.web3.TransactionInstruction
+      ) => {
+        const tx = new anchor.web3.Transaction();
+        if (memo) tx.add(memo);
+        tx.add(
+          await createTransferCheckedWithTransferHookInstruction(
+            connection,
+            user1Ata,
+            mintPda,
+            authorityAta,
+            user1.publicKey,
+            BigInt(amount),
+            6,
+            [],
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+          )
+        );
+        try {
+          await provider.sendAndConfirm(tx, [user1]);
+        } catch (e: any) {
+          return e?.message ?? e?.toString?.() ?? String(e);
+        }
+        return "";
+      };
+
+      const before = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(await transfer(2_000)).to.match(
+        /TravelRuleMemoMissing|travel-rule memo|0x1782/i
+      );
+      // A memo without the travel-rule hash does not count.
+      expect(
+        await transfer(
+          2_000,
+          new anchor.web3.TransactionInstruction({
+            programId: new anchor.web3.PublicKey(
+              "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+            ),
+            keys: [],
+            data: Buffer.from("invoice 42", "utf8"),
+          })
+        )
+      ).to.match(/TravelRuleMemoMissing|travel-rule memo|0x1782/i);
+      expect(
+        await transfer(
+          2_000,
+          createTravelRuleMemoInstruction(
+            JSON.stringify({ originator: "user1", beneficiary: "treasury" })
+          )
+        )
+      ).to.equal("");
+      // Each transfer needs its own memo directly before it: two transfers
+      // sharing one memo fail as a whole.
+      const transferIx = async () =>
+        createTransferCheckedWithTransferHookInstruction(
+          connection,
+          user1Ata,
+          mintPda,
+          authorityAta,
+          user1.publicKey,
+          BigInt(2_000),
+          6,
+          [],
+          undefined,
+          TOKEN_2022_PROGRAM_ID
+        );
+      let shared = "";
+      try {
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            createTravelRuleMemoInstruction("shared"),
+            await transferIx(),
+            await transferIx()
+          ),
+          [user1]
+        );
+      } catch (e: any) {
+        shared = e?.message ?? String(e);
+      }
+      expect(shared).to.match(
+        /TravelRuleMemoMissing|travel-rule memo|0x1782/i
+      );
+      // A memo after the transfer does not count either.
+      let trailing = "";
+      try {
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            await transferIx(),
+            createTravelRuleMemoInstruction("trailing")
+          ),
+          [user1]
+        );
+      } catch (e: any) {
+        trailing = e?.message ?? String(e);
+      }
+      expect(trailing).to.match(
+        /TravelRuleMemoMissing|travel-rule memo|0x1782/i
+      );
+      // At the threshold no memo is needed.
+      expect(await transfer(1_000)).to.equal("");
+      const after = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(after.amount) - Number(before.amount)).to.equal(3_000);
+
+      await sss2Sdk.setTravelRuleThreshold(authority.publicKey, null).rpc();
+      expect(await transfer(2_000)).to.equal("");
+    });
+
+    it("Blocks transfers while paused; seizure stays exempt", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      const user1Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const authorityAta = getAssociatedTokenAddressSync(
+        mintPda,
+        authority.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await sss2Sdk
+        .pause(authority.publicKey, PauseFlags.transfer, "exploit")
+        .then((tx) => tx.rpc());
+
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        user1Ata,
+        mintPda,
+        authorityAta,
+        user1.publicKey,
+        BigInt(1),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      let errMsg = "";
+      try {
+        await anchor.web3.sendAndConfirmTransaction(
+          connection,
+          new anchor.web3.Transaction().add(transferIx),
+          [user1]
+        );
+      } catch (e: any) {
+        errMsg = e?.message ?? e?.toString?.() ?? String(e);
+      }
+      expect(errMsg).to.match(/TransfersPaused|Transfers are paused|0x1772/i);
+
+      const before = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      await complianceSdk
+        .seize(authority.publicKey, user1.publicKey, authority.publicKey, 1)
+        .then((tx) => tx.rpc());
+      const after = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(after.amount) - Number(before.amount)).to.equal(1);
+
+      await sss2Sdk
+        .unpause(authority.publicKey, PauseFlags.transfer)
+        .then((tx) => tx.rpc());
+      const returnIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        authorityAta,
+        mintPda,
+        user1Ata,
+        authority.publicKey,
+        BigInt(1),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(returnIx));
+    });
+
     it("Pauses and Unpauses the stablecoin entirely", async () => {
       await sss2Sdk.pause(authority.publicKey).then((tx) => tx.rpc());
       const mintTx = await sss2Sdk.mint(
@@ -337,5 +798,95 @@ export function registerSSS2VanillaSuite(ctx: TestContext): void {
       await sss2Sdk.unpause(authority.publicKey).then((tx) => tx.rpc());
       await mintTx.rpc();
     });
+
+    it("default pause leaves seize available", async () => {
+      const authorityAta = getAssociatedTokenAddressSync(
+        sss2Sdk.mintAddress!,
+        authority.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await sss2Sdk.pause(authority.publicKey).then((tx) => tx.rpc());
+      const config = await sss2Sdk.getConfig();
+      expect(config.pauseFlags).to.equal(PauseFlags.default);
+      expect(config.pauseFlags & PauseFlags.seize).to.equal(0);
+
+      const before = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      await complianceSdk
+        .seize(authority.publicKey, user1.publicKey, authority.publicKey, 1)
+        .then((tx) => tx.rpc());
+      const after = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(after.amount) - Number(before.amount)).to.equal(1);
+      await sss2Sdk.unpause(authority.publicKey).then((tx) => tx.rpc());
+    });
+
+    it("default pause halts transfers but a seize still passes the hook", async () => {
+      const mintPda = sss2Sdk.mintAddress!;
+      const user1Ata = getAssociatedTokenAddressSync(
+        mintPda,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const authorityAta = getAssociatedTokenAddressSync(
+        mintPda,
+        authority.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await sss2Sdk.pause(authority.publicKey).then((tx) => tx.rpc());
+
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        user1Ata,
+        mintPda,
+        authorityAta,
+        user1.publicKey,
+        BigInt(1),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      let errMsg = "";
+      try {
+        await anchor.web3.sendAndConfirmTransaction(
+          connection,
+          new anchor.web3.Transaction().add(transferIx),
+          [user1]
+        );
+      } catch (e: any) {
+        errMsg = e?.message ?? e?.toString?.() ?? String(e);
+      }
+      expect(errMsg).to.match(/TransfersPaused|Transfers are paused|0x1772/i);
+
+      const before = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      await complianceSdk
+        .seize(authority.publicKey, user1.publicKey, authority.publicKey, 1)
+        .then((tx) => tx.rpc());
+      const after = await getAccount(
+        connection,
+        authorityAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(Number(after.amount) - Number(before.amount)).to.equal(1);
+      await sss2Sdk.unpause(authority.publicKey).then((tx) => tx.rpc());
+    });
   });
 }
@@ -6,12 +6,19 @@ import {
   createAssociatedTokenAccountInstruction,
   getAccount,
   createTransferCheckedWithTransferHookInstruction,
+  createExecuteInstruction,
+  getExtraAccountMetaAddress,
+  getMint,
+  getExtensionData,
+  ExtensionType,
 } from "@solana/spl-token";
 import {
   SolanaStablecoin,
   SSSComplianceModule,
   SSS_3_PRESET,
   SSS3ConfidentialModule,
+  PauseFlags,
+  fetchCpiEvents,
 } from "../../sdk/src";
 import type { TestContext } from "../context";
 
@@ -96,8 +103,7 @@ export function registerSSS3AllowlistSuite(ctx: TestContext): void {
     it("Initializes transfer hook extra accounts with allowlist", async () => {
       const compliance = new SSSComplianceModule(sss3Sdk);
       const initHook = await compliance.initializeTransferHookExtraAccounts(
-        authority.publicKey,
-        true
+        authority.publicKey
       );
       const sig = await initHook.rpc();
       const latestBlockhash = await connection.getLatestBlockhash();
@@ -154,6 +160,53 @@ export function registerSSS3AllowlistSuite(ctx: TestContext): void {
       expect(entryAfter.isAllowed).to.be.false;
     });
 
+    it("allowlister role can manage the allowlist; other keys cannot", async () => {
+      const allowlister = anchor.web3.Keypair.generate();
+      const airdropSig = await connection.requestAirdrop(
+        allowlister.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await connection.confirmTransaction(
+        { signature: airdropSig, ...(await connection.getLatestBlockhash()) },
+        "confirmed"
+      );
+      await sss3Sdk
+        .updateRoles(authority.publicKey, {
+          allowlister: allowlister.publicKey,
+        })
+        .then((tx) => tx.rpc());
+      const roles = await sss3Sdk.getRoles();
+      expect(roles.allowlister.equals(allowlister.publicKey)).to.be.true;
+
+      const wallet = anchor.web3.Keypair.generate().publicKey;
+      await sss3Sdk
+        .addToAllowlist(allowlister.publicKey, wallet)
+        .then((tx) => tx.signers([allowlister]).rpc());
+      const entry = await stablecoinProgram.account.allowlistEntry.fetch(
+        SolanaStablecoin.getAllowlistEntryPDA(
+          pusdMint,
+          wallet,
+          stablecoinProgram.programId
+        )
+      );
+      expect(entry.isAllowed).to.be.true;
+
+      let err: unknown;
+      try {
+        await sss3Sdk
+          .removeFromAllowlist(user2.publicKey, wallet)
+          .then((tx) => tx.signers([user2]).rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include("Unauthorized");
+
+      await sss3Sdk
+        .updateRoles(authority.publicKey, { allowlister: authority.publicKey })
+        .then((tx) => tx.rpc());
+    });
+
     it("transfer fails when destination is not on allowlist, then succeeds after adding", async () => {
       const addUser1Sig = await addOrUpdateAllowlist(
         sss3Sdk,
@@ -320,6 +373,834 @@ export function registerSSS3AllowlistSuite(ctx: TestContext): void {
       expect(Number(user2Acc.amount)).to.equal(50_000);
     });
 
+    it("expired allowlist entry blocks transfers; past expiry is rejected", async () => {
+      const chainTime = async () =>
+        (await connection.getBlockTime(
+          await connection.getSlot("confirmed")
+        )) ?? Math.floor(Date.now() / 1000);
+
+      let err: unknown;
+      try {
+        await sss3Sdk
+          .updateAllowlistEntry(
+            authority.publicKey,
+            user2.publicKey,
+            true,
+            (await chainTime()) - 60
+          )
+          .rpc();
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "InvalidExpiry"
+      );
+
+      const expiresAt = (await chainTime()) + 3;
+      await sss3Sdk
+        .updateAllowlistEntry(
+          authority.publicKey,
+          user2.publicKey,
+          true,
+          expiresAt
+        )
+        .rpc();
+      const entry = await stablecoinProgram.account.allowlistEntry.fetch(
+        SolanaStablecoin.getAllowlistEntryPDA(
+          pusdMint,
+          user2.publicKey,
+          stablecoinProgram.programId
+        )
+      );
+      expect(entry.isAllowed).to.be.true;
+      expect(entry.expiresAt?.toNumber()).to.equal(expiresAt);
+
+      while ((await chainTime()) <= expiresAt) {
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+      }
+      const user1PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        user1PusdAta,
+        pusdMint,
+        user2PusdAta,
+        user1.publicKey,
+        BigInt(1_000),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      let errMsg = "";
+      try {
+        await anchor.web3.sendAndConfirmTransaction(
+          connection,
+          new anchor.web3.Transaction().add(transferIx),
+          [user1]
+        );
+      } catch (e: any) {
+        errMsg = e?.message ?? e?.toString?.() ?? String(e);
+      }
+      expect(errMsg).to.match(
+        /NotOnAllowlist|not on the allowlist|0x1771|6001/i
+      );
+
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user2.publicKey, true)
+        .rpc();
+    });
+
+    it("transfer pause blocks transfers while minting continues", async () => {
+      await sss3Sdk
+        .pause(authority.publicKey, PauseFlags.transfer, "incident")
+        .then((tx) => tx.rpc());
+      const config = await sss3Sdk.getConfig();
+      expect(config.pauseFlags).to.equal(PauseFlags.transfer);
+      expect(config.pauseReason).to.equal("incident");
+      expect(config.pausedAt).to.be.greaterThan(0);
+
+      const user1PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transfer = async () =>
+        anchor.web3.sendAndConfirmTransaction(
+          connection,
+          new anchor.web3.Transaction().add(
+            await createTransferCheckedWithTransferHookInstruction(
+              connection,
+              user1PusdAta,
+              pusdMint,
+              user2PusdAta,
+              user1.publicKey,
+              BigInt(1_000),
+              6,
+              [],
+              undefined,
+              TOKEN_2022_PROGRAM_ID
+            )
+          ),
+          [user1]
+        );
+      let errMsg = "";
+      try {
+        await transfer();
+      } catch (e: any) {
+        errMsg = e?.message ?? e?.toString?.() ?? String(e);
+      }
+      expect(errMsg).to.match(/TransfersPaused|Transfers are paused|0x1772/i);
+
+      await sss3Sdk
+        .mint(authority.publicKey, user1.publicKey, 1_000)
+        .then((tx) => tx.rpc());
+
+      await sss3Sdk
+        .unpause(authority.publicKey, PauseFlags.transfer)
+        .then((tx) => tx.rpc());
+      expect((await sss3Sdk.getConfig()).isPaused).to.be.false;
+      await transfer();
+    });
+
+    it("set_allowlist_enabled + update_extra_account_meta_list toggle enforcement", async () => {
+      const compliance = new SSSComplianceModule(sss3Sdk);
+      const extraListPda = SolanaStablecoin.getExtraAccountMetaListPDA(
+        pusdMint,
+        transferHookProgram.programId
+      );
+      const toggle = async (enabled: boolean) => {
+        const updateHookIx = await compliance
+          .updateTransferHookExtraAccounts(authority.publicKey)
+          .instruction();
+        await sss3Sdk
+          .setAllowlistEnabled(authority.publicKey, enabled)
+          .postInstructions([updateHookIx])
+          .rpc();
+      };
+
+      let err: unknown;
+      try {
+        await compliance
+          .updateTransferHookExtraAccounts(user2.publicKey)
+          .signers([user2])
+          .rpc();
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include("Unauthorized");
+
+      await sss3Sdk
+        .removeFromAllowlist(authority.publicKey, user2.publicKey)
+        .then((tx) => tx.rpc());
+      const sizeEnabled = (await connection.getAccountInfo(extraListPda))!.data
+        .length;
+      await toggle(false);
+      expect((await sss3Sdk.getConfig()).enableAllowlist).to.be.false;
+      expect(
+        (await connection.getAccountInfo(extraListPda))!.data.length
+      ).to.be.lessThan(sizeEnabled);
+
+      const user1PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transfer = async () =>
+        anchor.web3.sendAndConfirmTransaction(
+          connection,
+          new anchor.web3.Transaction().add(
+            await createTransferCheckedWithTransferHookInstruction(
+              connection,
+              user1PusdAta,
+              pusdMint,
+              user2PusdAta,
+              user1.publicKey,
+              BigInt(1_000),
+              6,
+              [],
+              undefined,
+              TOKEN_2022_PROGRAM_ID
+            )
+          ),
+          [user1]
+        );
+      await transfer();
+
+      await toggle(true);
+      expect(
+        (await connection.getAccountInfo(extraListPda))!.data.length
+      ).to.equal(sizeEnabled);
+      let errMsg = "";
+      try {
+        await transfer();
+      } catch (e: any) {
+        errMsg = e?.message ?? e?.toString?.() ?? String(e);
+      }
+      expect(errMsg).to.match(
+        /NotOnAllowlist|not on the allowlist|0x1771|6001/i
+      );
+
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user2.publicKey, true)
+        .rpc();
+    });
+
+    it("hook rejects allowlist accounts that are not the wallet's PDA", async () => {
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user2.publicKey, false)
+        .rpc();
+      const user1PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transferIx = await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        user1PusdAta,
+        pusdMint,
+        user2PusdAta,
+        user1.publicKey,
+        BigInt(1_000),
+        6,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      // extras: 0 stablecoin program, 1-2 blacklists, 3 config, 4-5 allowlists
+      const extras = transferIx.keys.slice(4, transferIx.keys.length - 2);
+      const execute = async (destAllowlist: anchor.web3.PublicKey) => {
+        const ix = createExecuteInstruction(
+          transferHookProgram.programId,
+          user1PusdAta,
+          pusdMint,
+          user2PusdAta,
+          user1.publicKey,
+          getExtraAccountMetaAddress(pusdMint, transferHookProgram.programId),
+          BigInt(1_000)
+        );
+        ix.keys.push(...extras.slice(0, 5), {
+          ...extras[5],
+          pubkey: destAllowlist,
+        });
+        try {
+          await provider.sendAndConfirm(new anchor.web3.Transaction().add(ix));
+        } catch (e: any) {
+          return e?.message ?? e?.toString?.() ?? String(e);
+        }
+        return "";
+      };
+
+      // user1's genuine entry (is_allowed = true) in place of user2's.
+      const user1Allowlist = SolanaStablecoin.getAllowlistEntryPDA(
+        pusdMint,
+        user1.publicKey,
+        stablecoinProgram.programId
+      );
+      expect(
+        (await stablecoinProgram.account.allowlistEntry.fetch(user1Allowlist))
+          .isAllowed
+      ).to.be.true;
+      expect(await execute(user1Allowlist)).to.match(
+        /InvalidAllowlistAccount|not the PDA for this mint and wallet|0x1777/i
+      );
+
+      // An account the stablecoin program does not own.
+      const fake = anchor.web3.Keypair.generate();
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.createAccount({
+            fromPubkey: authority.publicKey,
+            newAccountPubkey: fake.publicKey,
+            lamports: await connection.getMinimumBalanceForRentExemption(64),
+            space: 64,
+            programId: anchor.web3.SystemProgram.programId,
+          })
+        ),
+        [fake]
+      );
+      expect(await execute(fake.publicKey)).to.match(
+        /InvalidAccountOwner|not owned by the stablecoin program|0x1775/i
+      );
+
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user2.publicKey, true)
+        .rpc();
+    });
+
+    it("transfer limits cap single transfers and the rolling daily total", async () => {
+      const compliance = new SSSComplianceModule(sss3Sdk);
+      await sss3Sdk
+        .setTransferLimitTier(authority.publicKey, 1, 3_000, 2_000)
+        .rpc();
+      expect((await sss3Sdk.getConfig()).transferLimitTiers[1]).to.deep.equal({
+        dailyLimit: BigInt(3_000),
+        perTransferLimit: BigInt(2_000),
+      });
+      await compliance
+        .initializeTransferLimit(authority.publicKey, user1.publicKey, 1)
+        .rpc();
+
+      const user1PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transfer = async (amount: number) => {
+        try {
+          await anchor.web3.sendAndConfirmTransaction(
+            connection,
+            new anchor.web3.Transaction().add(
+              await createTransferCheckedWithTransferHookInstruction(
+                connection,
+                user1PusdAta,
+                pusdMint,
+                user2PusdAta,
+                user1.publicKey,
+                BigInt(amount),
+                6,
+                [],
+                undefined,
+                TOKEN_2022_PROGRAM_ID
+              )
+            ),
+            [user1]
+          );
+        } catch (e: any) {
+          return e?.message ?? e?.toString?.() ?? String(e);
+        }
+        return "";
+      };
+
+      expect(await transfer(2_500)).to.match(
+        /PerTransferLimitExceeded|per-transfer limit|0x177d/i
+      );
+      expect(await transfer(2_000)).to.equal("");
+      expect(await transfer(1_500)).to.match(
+        /DailyTransferLimitExceeded|daily limit|0x177e/i
+      );
+      expect(await transfer(1_000)).to.equal("");
+
+      // A per-wallet override replaces the tier's cap.
+      await compliance
+        .updateTransferLimit(authority.publicKey, user1.publicKey, 1, 10_000)
+        .rpc();
+      expect(await transfer(1_500)).to.equal("");
+
+      // Closing the limit refunds its rent and leaves user1 uncapped for later tests.
+      await compliance
+        .closeTransferLimit(authority.publicKey, user1.publicKey)
+        .rpc();
+      expect(
+        await connection.getAccountInfo(
+          SolanaStablecoin.getTransferLimitPDA(
+            pusdMint,
+            user1.publicKey,
+            transferHookProgram.programId
+          )
+        )
+      ).to.be.null;
+      expect(await transfer(2_500)).to.equal("");
+    });
+
+    it("allowlist policy tiers cap transfer amounts and restrict counterparties", async () => {
+      await sss3Sdk.initializeAllowlistPolicy(authority.publicKey).rpc();
+      // Tier 1 may send up to 5_000 per transfer, and only to tier 2.
+      await sss3Sdk
+        .setAllowlistTier(authority.publicKey, 1, 5_000, [2])
+        .rpc();
+      const policy = await stablecoinProgram.account.allowlistPolicy.fetch(
+        SolanaStablecoin.getAllowlistPolicyPDA(
+          pusdMint,
+          stablecoinProgram.programId
+        )
+      );
+      expect(policy.tiers[1].maxTransferAmount.toNumber()).to.equal(5_000);
+      expect(policy.tiers[1].allowedRecipientTiers).to.equal(0b100);
+
+      let outOfRange = "";
+      try {
+        await sss3Sdk
+          .updateAllowlistEntry(authority.publicKey, user1.publicKey, true, null, 8)
+          .rpc();
+      } catch (e: any) {
+        outOfRange = e?.message ?? String(e);
+      }
+      expect(outOfRange).to.match(/InvalidAllowlistTier|out of range/i);
+
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user1.publicKey, true, null, 1)
+        .rpc();
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user2.publicKey, true)
+        .rpc();
+
+      const user1PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user1.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const user2PusdAta = getAssociatedTokenAddressSync(
+        pusdMint,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const transfer = async (amount: number) => {
+        try {
+          await anchor.web3.sendAndConfirmTransaction(
+            connection,
+            new anchor.web3.Transaction().add(
+              await createTransferCheckedWithTransferHookInstruction(
+                connection,
+                user1PusdAta,
+                pusdMint,
+                user2PusdAta,
+                user1.publicKey,
+                BigInt(amount),
+                6,
+                [],
+                undefined,
+                TOKEN_2022_PROGRAM_ID
+              )
+            ),
+            [user1]
+          );
+        } catch (e: any) {
+          return e?.message ?? e?.toString?.() ?? String(e);
+        }
+        return "";
+      };
+
+      // user2 is tier 0, which tier 1 may not send to.
+      expect(await transfer(1_000)).to.match(
+        /CounterpartyTierNotAllowed|may not send|0x1781/i
+      );
+
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user2.publicKey, true, null, 2)
+        .rpc();
+      expect(await transfer(6_000)).to.match(
+        /AllowlistTierAmountExceeded|tier cap|0x1780/i
+      );
+      expect(await transfer(1_000)).to.equal("");
+
+      // Tier 0 is unrestricted; put both wallets back for later tests.
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user1.publicKey, true)
+        .rpc();
+      await sss3Sdk
+        .updateAllowlistEntry(authority.publicKey, user2.publicKey, true)
+        .rpc();
+    });
+
+    it("confidential mint requires approval; master rotates the auditor key", async () => {
+      const confidential = sss3Sdk.getConfidential();
+      // ConfidentialTransferMint: authority (32) | auto_approve (1) | auditor (32)
+      const readExtension = async () => {
+        const mint = await getMint(
+          connection,
+          pusdMint,
+          "confirmed",
+          TOKEN_2022_PROGRAM_ID
+        );
+        const data = getExtensionData(
+          ExtensionType.ConfidentialTransferMint,
+          mint.tlvData
+        )!;
+        return {
+          authority: new anchor.web3.PublicKey(data.subarray(0, 32)),
+          autoApprove: data[32] === 1,
+          auditor: Buffer.from(data.subarray(33, 65)),
+        };
+      };
+      const initial = await readExtension();
+      expect(
+        initial.authority.equals(
+          SolanaStablecoin.getConfigPDA(pusdMint, stablecoinProgram.programId)
+        )
+      ).to.be.true;
+      // SSS_3_PRESET disables auto-approval.
+      expect(initial.autoApprove).to.be.false;
+
+      const auditor = new Uint8Array(32).fill(7);
+      let errMsg = "";
+      try {
+        await confidential
+          .updateMint(user1.publicKey, true, auditor)
+          .signers([user1])
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/Unauthorized|0x1771/i);
+
+      const updateSig = await confidential
+        .updateMint(authority.publicKey, false, auditor)
+        .rpc({ commitment: "confirmed" });
+      const [mintUpdated] = await fetchCpiEvents(
+        connection,
+        stablecoinProgram,
+        updateSig
+      );
+      expect(mintUpdated.name).to.equal("confidentialMintUpdated");
+      expect(mintUpdated.data.autoApproveNewAccounts).to.be.false;
+      expect(mintUpdated.data.auditorElgamalPubkey).to.deep.equal(
+        Array.from(auditor)
+      );
+      let updated = await readExtension();
+      expect(updated.autoApprove).to.be.false;
+      expect(updated.auditor.equals(Buffer.from(auditor))).to.be.true;
+
+      // Base64 form, as printed by the Solana CLI.
+      await confidential
+        .updateMint(
+          authority.publicKey,
+          true,
+          Buffer.from(auditor).toString("base64")
+        )
+        .rpc();
+      updated = await readExtension();
+      expect(updated.autoApprove).to.be.true;
+
+      await confidential.updateMint(authority.publicKey, false, null).rpc();
+      updated = await readExtension();
+      expect(updated.auditor.equals(Buffer.alloc(32))).to.be.true;
+
+      // Only the allowlister or master may approve, and only configured accounts.
+      errMsg = "";
+      try {
+        await confidential
+          .approveAccount(user2.publicKey, user1.publicKey)
+          .signers([user2])
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/Unauthorized|0x1771/i);
+      errMsg = "";
+      try {
+        await confidential
+          .approveAccount(authority.publicKey, user1.publicKey)
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.not.equal("");
+    });
+
+    it("ConfidentialMintBurn is opt-in at init and gates confidential mint/burn", async () => {
+      const supplyElGamalPubkey = new Uint8Array(32).fill(9);
+      const confidentialMintBurn = {
+        supplyElGamalPubkey,
+        decryptableSupply: new Uint8Array(36),
+      };
+      const initSdk = new SolanaStablecoin(
+        stablecoinProgram,
+        undefined,
+        transferHookProgram
+      );
+
+      // The extension rides on ConfidentialTransferMint, so it cannot be enabled alone.
+      let errMsg = "";
+      try {
+        await (
+          await initSdk.initialize(
+            authority.publicKey,
+            {
+              name: "No Confidential",
+              symbol: "PCMX",
+              uri: "https://example.com/sss3",
+              decimals: 6,
+              ...SSS_3_PRESET,
+              enableConfidentialTransfers: false,
+              confidentialMintBurn,
+            },
+            transferHookProgram.programId
+          )
+        ).rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/ConfidentialTransfersNotEnabled|0x1777/i);
+
+      await (
+        await initSdk.initialize(
+          authority.publicKey,
+          {
+            name: "Confidential Mint USD",
+            symbol: "PCMB",
+            uri: "https://example.com/sss3",
+            decimals: 6,
+            ...SSS_3_PRESET,
+            confidentialMintBurn: {
+              ...confidentialMintBurn,
+              supplyElGamalPubkey: Buffer.from(supplyElGamalPubkey).toString(
+                "base64"
+              ),
+            },
+          },
+          transferHookProgram.programId
+        )
+      ).rpc();
+      const cmbMint = SolanaStablecoin.getMintPDA(
+        "PCMB",
+        stablecoinProgram.programId
+      );
+      const cmbSdk = new SolanaStablecoin(
+        stablecoinProgram,
+        cmbMint,
+        transferHookProgram
+      );
+      expect((await cmbSdk.getConfig()).enableConfidentialMintBurn).to.be.true;
+      expect((await sss3Sdk.getConfig()).enableConfidentialMintBurn).to.be.false;
+
+      // ConfidentialMintBurn (TLV type 24): confidential_supply (64) | decryptable_supply (36) | supply_elgamal_pubkey (32)
+      const mintInfo = await getMint(
+        connection,
+        cmbMint,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      const extension = getExtensionData(24 as ExtensionType, mintInfo.tlvData)!;
+      expect(
+        Buffer.from(extension.subarray(100, 132)).equals(
+          Buffer.from(supplyElGamalPubkey)
+        )
+      ).to.be.true;
+
+      const proofs = {
+        equality: anchor.web3.Keypair.generate().publicKey,
+        ciphertextValidity: anchor.web3.Keypair.generate().publicKey,
+        range: anchor.web3.Keypair.generate().publicKey,
+        amountOpening: { lo: new Uint8Array(32), hi: new Uint8Array(32) },
+      };
+      const user2CmbAta = getAssociatedTokenAddressSync(
+        cmbMint,
+        user2.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            authority.publicKey,
+            user2CmbAta,
+            user2.publicKey,
+            cmbMint,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+
+      // Mints without the extension reject both instructions up front.
+      errMsg = "";
+      try {
+        await sss3Sdk
+          .getConfidential()
+          .confidentialMint(
+            authority.publicKey,
+            user1.publicKey,
+            1_000_000,
+            new Uint8Array(36),
+            proofs
+          )
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/ConfidentialMintBurnNotEnabled|0x1786/i);
+
+      // Same role checks as mint / burn: a minter config is required, and only the burner or master may burn.
+      errMsg = "";
+      try {
+        await cmbSdk
+          .getConfidential()
+          .confidentialMint(
+            user1.publicKey,
+            user1.publicKey,
+            1_000_000,
+            new Uint8Array(36),
+            proofs,
+            user2CmbAta
+          )
+          .signers([user1])
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/AccountNotInitialized|0xbc4/i);
+
+      // The declared amount must open the validity proof's commitments, so an active minter
+      // cannot charge a different amount than it mints; nothing is charged when it does not.
+      await cmbSdk
+        .addMinter(authority.publicKey, authority.publicKey, true, 5_000_000)
+        .rpc();
+      errMsg = "";
+      try {
+        await cmbSdk
+          .getConfidential()
+          .confidentialMint(
+            authority.publicKey,
+            user2.publicKey,
+            1_000_000,
+            new Uint8Array(36),
+            proofs,
+            user2CmbAta
+          )
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/ConfidentialAmountMismatch|0x1788/i);
+
+      // Token-2022 refuses public MintTo once ConfidentialMintBurn is on; the program says so first.
+      errMsg = "";
+      try {
+        await cmbSdk
+          .mint(authority.publicKey, user2.publicKey, 1_000_000)
+          .then((tx) => tx.rpc());
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/PublicMintDisabled|0x178b/i);
+      const cmbMinter = await stablecoinProgram.account.minterConfig.fetch(
+        SolanaStablecoin.getMinterPDA(
+          cmbMint,
+          authority.publicKey,
+          stablecoinProgram.programId
+        )
+      );
+      expect(cmbMinter.totalMinted.toNumber()).to.equal(0);
+      expect((await cmbSdk.getConfig()).confidentialSupply).to.equal(0n);
+
+      // Direct Token-2022 burns are invisible to the program; the master writes back the real supply.
+      errMsg = "";
+      try {
+        await sss3Sdk
+          .getConfidential()
+          .reconcileSupply(authority.publicKey, 0)
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/ConfidentialMintBurnNotEnabled|0x1786/i);
+      errMsg = "";
+      try {
+        await cmbSdk
+          .getConfidential()
+          .reconcileSupply(user1.publicKey, 0)
+          .signers([user1])
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/Unauthorized|0x1771/i);
+      await cmbSdk
+        .getConfidential()
+        .reconcileSupply(authority.publicKey, 250_000)
+        .rpc();
+      expect((await cmbSdk.getConfig()).confidentialSupply).to.equal(250_000n);
+      await cmbSdk
+        .getConfidential()
+        .reconcileSupply(authority.publicKey, 0)
+        .rpc();
+      expect((await cmbSdk.getConfig()).confidentialSupply).to.equal(0n);
+
+      errMsg = "";
+      try {
+        await cmbSdk
+          .getConfidential()
+          .confidentialBurn(user2.publicKey, 1_000_000, new Uint8Array(36), proofs)
+          .signers([user2])
+          .rpc();
+      } catch (e: any) {
+        errMsg = e?.message ?? String(e);
+      }
+      expect(errMsg).to.match(/Unauthorized|0x1771/i);
+    });
+
     it("getConfidential returns SSS3ConfidentialModule and fundConfidential rejects when not on allowlist", async () => {
       const confidential = sss3Sdk.getConfidential();
       expect(confidential).to.be.instanceOf(SSS3ConfidentialModule);
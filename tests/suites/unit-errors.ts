@@ -112,6 +112,130 @@ export function registerUnitErrorsSuite(ctx: TestContext): void {
       expect((err as { message?: string }).message).to.include("QuotaExceeded");
     });
 
+    it("mint beyond remaining allowance returns AllowanceExceeded", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const allowanceMinter = anchor.web3.Keypair.generate();
+      const airdropSig = await connection.requestAirdrop(
+        allowanceMinter.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      const lb = await connection.getLatestBlockhash();
+      await connection.confirmTransaction(
+        { signature: airdropSig, ...lb },
+        "confirmed"
+      );
+      await sss2Sdk
+        .addMinter(
+          authority.publicKey,
+          allowanceMinter.publicKey,
+          true,
+          0,
+          null,
+          "allowance"
+        )
+        .then((tx) => tx.rpc());
+      await sss2Sdk
+        .increaseMinterAllowance(
+          authority.publicKey,
+          allowanceMinter.publicKey,
+          50
+        )
+        .rpc();
+      const allowanceMinterAta = getAssociatedTokenAddressSync(
+        mintPda,
+        allowanceMinter.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            authority.publicKey,
+            allowanceMinterAta,
+            allowanceMinter.publicKey,
+            mintPda,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+      await sss2Sdk
+        .mint(allowanceMinter.publicKey, allowanceMinter.publicKey, 50)
+        .then((tx) => tx.signers([allowanceMinter]).rpc());
+      let err: unknown;
+      try {
+        await sss2Sdk
+          .mint(allowanceMinter.publicKey, allowanceMinter.publicKey, 1)
+          .then((tx) => tx.signers([allowanceMinter]).rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "AllowanceExceeded"
+      );
+    });
+
+    it("minter window that is not a positive multiple of 24 s returns InvalidMintWindow", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const minterKp = anchor.web3.Keypair.generate();
+      let err: unknown;
+      try {
+        await sss2Sdk
+          .addMinter(authority.publicKey, minterKp.publicKey, true, 100, 23)
+          .then((tx) => tx.rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "InvalidMintWindow"
+      );
+
+      // 25 s would have truncated to 1 s buckets, a 24 s window in practice.
+      err = undefined;
+      try {
+        await sss2Sdk
+          .addMinter(authority.publicKey, minterKp.publicKey, true, 100, 25)
+          .then((tx) => tx.rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "InvalidMintWindow"
+      );
+
+      await sss2Sdk
+        .addMinter(authority.publicKey, minterKp.publicKey, true, 100, 24)
+        .then((tx) => tx.rpc());
+      err = undefined;
+      try {
+        await sss2Sdk
+          .updateMinterQuota(
+            authority.publicKey,
+            minterKp.publicKey,
+            true,
+            100,
+            100
+          )
+          .rpc();
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "InvalidMintWindow"
+      );
+    });
+
     it("mint with inactive minter returns MinterInactive", async () => {
       const mintPda = SolanaStablecoin.getMintPDA(
         "SUSD",
@@ -168,6 +292,56 @@ export function registerUnitErrorsSuite(ctx: TestContext): void {
       expect((err as { message?: string }).message).to.include("InvalidAmount");
     });
 
+    it("accept_authority by a key that was not proposed returns Unauthorized", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const proposed = anchor.web3.Keypair.generate();
+      await sss2Sdk
+        .proposeAuthority(authority.publicKey, "master", proposed.publicKey)
+        .rpc();
+      let err: unknown;
+      try {
+        await sss2Sdk
+          .acceptAuthority(user1.publicKey, "master")
+          .signers([user1])
+          .rpc();
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include("Unauthorized");
+      await sss2Sdk
+        .cancelAuthorityTransfer(authority.publicKey, "master")
+        .rpc();
+    });
+
+    it("mint above max_supply returns SupplyCapExceeded", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const supply = await sss2Sdk.getTotalSupply();
+      await sss2Sdk.setMaxSupply(authority.publicKey, supply.toString()).rpc();
+      expect(await sss2Sdk.getSupplyHeadroom()).to.equal(BigInt(0));
+      let err: unknown;
+      try {
+        await sss2Sdk
+          .mint(authority.publicKey, user1.publicKey, 1)
+          .then((tx) => tx.rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "SupplyCapExceeded"
+      );
+      await sss2Sdk.setMaxSupply(authority.publicKey, null).rpc();
+    });
+
     it("mint when paused returns Paused", async () => {
       const mintPda = SolanaStablecoin.getMintPDA(
         "SUSD",
@@ -187,5 +361,18 @@ export function registerUnitErrorsSuite(ctx: TestContext): void {
       expect((err as { message?: string }).message).to.include("Paused");
       await sss2Sdk.unpause(authority.publicKey).then((tx) => tx.rpc());
     });
+
+    it("pause with an empty mask returns InvalidPauseFlags", async () => {
+      let err: unknown;
+      try {
+        await sss2Sdk.pause(authority.publicKey, 0).then((tx) => tx.rpc());
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include(
+        "InvalidPauseFlags"
+      );
+    });
   });
 }
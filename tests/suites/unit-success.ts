@@ -4,13 +4,37 @@ import {
   TOKEN_2022_PROGRAM_ID,
   getAssociatedTokenAddressSync,
   getAccount,
+  getTokenMetadata,
+  createAssociatedTokenAccountInstruction,
   createTransferCheckedWithTransferHookInstruction,
 } from "@solana/spl-token";
 import { SolanaStablecoin } from "../../sdk/src";
 import type { TestContext } from "../context";
 
+/** Cluster time from the Clock sysvar (`unix_timestamp` sits at byte offset 32). */
+async function clockUnixTimestamp(
+  connection: anchor.web3.Connection
+): Promise<number> {
+  const info = await connection.getAccountInfo(
+    anchor.web3.SYSVAR_CLOCK_PUBKEY,
+    "confirmed"
+  );
+  return Number(info!.data.readBigInt64LE(32));
+}
+
+/** Poll until cluster time reaches `unixTimestamp`. */
+async function waitForClock(
+  connection: anchor.web3.Connection,
+  unixTimestamp: number
+): Promise<void> {
+  while ((await clockUnixTimestamp(connection)) < unixTimestamp) {
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+  }
+}
+
 export function registerUnitSuccessSuite(ctx: TestContext): void {
   const {
+    provider,
     connection,
     authority,
     stablecoinProgram,
@@ -52,6 +76,36 @@ export function registerUnitSuccessSuite(ctx: TestContext): void {
         .then((tx) => tx.rpc());
     });
 
+    it("update_metadata updates uri in config and mint metadata", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const before = await sss2Sdk.getConfig();
+      const newUri = "https://example.com/susd-v2.json";
+      await sss2Sdk
+        .updateMetadata(authority.publicKey, {
+          uri: newUri,
+          additionalMetadata: [{ key: "issuer", value: "SSS Test Issuer" }],
+        })
+        .rpc();
+      const after = await sss2Sdk.getConfig();
+      expect(after.uri).to.equal(newUri);
+      expect(after.name).to.equal(before.name);
+      const metadata = await getTokenMetadata(
+        connection,
+        mintPda,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(metadata?.uri).to.equal(newUri);
+      expect(metadata?.additionalMetadata).to.deep.include([
+        "issuer",
+        "SSS Test Issuer",
+      ]);
+    });
+
     it("configure_minter sets is_active and daily_mint_quota", async () => {
       const mintPda = SolanaStablecoin.getMintPDA(
         "SUSD",
@@ -75,15 +129,187 @@ export function registerUnitSuccessSuite(ctx: TestContext): void {
       expect(Number(minterConfig.dailyMintQuota)).to.equal(quota);
     });
 
-    it("transfer_authority updates master_authority", async () => {
+    it("rolling window keeps counting past the old fixed reset until buckets expire", async () => {
       const mintPda = SolanaStablecoin.getMintPDA(
         "SUSD",
         stablecoinProgram.programId
       );
-      const configPda = SolanaStablecoin.getConfigPDA(
+      sss2Sdk.mintAddress = mintPda;
+      const minterKp = anchor.web3.Keypair.generate();
+      // 24 s is the shortest window: 24 one-second buckets.
+      await sss2Sdk
+        .addMinter(authority.publicKey, minterKp.publicKey, true, 100, 24)
+        .then((tx) => tx.rpc());
+      const minterAta = getAssociatedTokenAddressSync(
+        mintPda,
+        minterKp.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            authority.publicKey,
+            minterAta,
+            minterKp.publicKey,
+            mintPda,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+      const minterPda = SolanaStablecoin.getMinterPDA(
+        mintPda,
+        minterKp.publicKey,
+        stablecoinProgram.programId
+      );
+      const mintAs = (amount: number) =>
+        sss2Sdk
+          .mint(minterKp.publicKey, minterKp.publicKey, amount)
+          .then((tx) =>
+            tx.signers([minterKp]).rpc({ commitment: "confirmed" })
+          );
+      const lastMint = async () =>
+        Number(
+          (await stablecoinProgram.account.minterConfig.fetch(minterPda))
+            .lastMintTimestamp
+        );
+
+      await mintAs(50);
+      const t0 = await lastMint();
+      await waitForClock(connection, t0 + 12);
+      await mintAs(50);
+      const t1 = await lastMint();
+
+      // A fixed window opened by the first mint would have reset by now; the rolling
+      // window still holds the second 50.
+      await waitForClock(connection, t0 + 25);
+      let err: unknown;
+      try {
+        await mintAs(100);
+      } catch (e) {
+        err = e;
+      }
+      expect(err).to.be.ok;
+      expect((err as { message?: string }).message).to.include("QuotaExceeded");
+
+      await waitForClock(connection, t1 + 25);
+      await mintAs(100);
+      const minterConfig = await stablecoinProgram.account.minterConfig.fetch(
+        minterPda
+      );
+      expect(Number(minterConfig.dailyMinted)).to.equal(100);
+    });
+
+    it("update_minter sets window_seconds and carries usage into the new window", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const minterKp = anchor.web3.Keypair.generate();
+      await sss2Sdk
+        .addMinter(authority.publicKey, minterKp.publicKey, true, 100)
+        .then((tx) => tx.rpc());
+      const minterAta = getAssociatedTokenAddressSync(
+        mintPda,
+        minterKp.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            authority.publicKey,
+            minterAta,
+            minterKp.publicKey,
+            mintPda,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+      await sss2Sdk
+        .mint(minterKp.publicKey, minterKp.publicKey, 40)
+        .then((tx) => tx.signers([minterKp]).rpc());
+
+      await sss2Sdk
+        .updateMinterQuota(
+          authority.publicKey,
+          minterKp.publicKey,
+          true,
+          100,
+          3600
+        )
+        .rpc();
+      const minterPda = SolanaStablecoin.getMinterPDA(
+        mintPda,
+        minterKp.publicKey,
+        stablecoinProgram.programId
+      );
+      let minterConfig = await stablecoinProgram.account.minterConfig.fetch(
+        minterPda
+      );
+      expect(minterConfig.windowSeconds).to.equal(3600);
+      expect(Number(minterConfig.dailyMinted)).to.equal(40);
+
+      // Omitting the window keeps it.
+      await sss2Sdk
+        .updateMinterQuota(authority.publicKey, minterKp.publicKey, true, 200)
+        .rpc();
+      minterConfig = await stablecoinProgram.account.minterConfig.fetch(
+        minterPda
+      );
+      expect(minterConfig.windowSeconds).to.equal(3600);
+      expect(Number(minterConfig.dailyMintQuota)).to.equal(200);
+    });
+
+    it("increase/decrease_minter_allowance adjusts an allowance-mode minter", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const minterKp = anchor.web3.Keypair.generate();
+      await sss2Sdk
+        .addMinter(
+          authority.publicKey,
+          minterKp.publicKey,
+          true,
+          0,
+          null,
+          "allowance"
+        )
+        .then((tx) => tx.rpc());
+      await sss2Sdk
+        .increaseMinterAllowance(authority.publicKey, minterKp.publicKey, 1000)
+        .rpc();
+      await sss2Sdk
+        .decreaseMinterAllowance(authority.publicKey, minterKp.publicKey, 400)
+        .rpc();
+      const minterPda = SolanaStablecoin.getMinterPDA(
         mintPda,
+        minterKp.publicKey,
+        stablecoinProgram.programId
+      );
+      let minterConfig = await stablecoinProgram.account.minterConfig.fetch(
+        minterPda
+      );
+      expect(minterConfig.quotaMode).to.deep.equal({ allowance: {} });
+      expect(Number(minterConfig.mintAllowance)).to.equal(600);
+      await sss2Sdk
+        .decreaseMinterAllowance(authority.publicKey, minterKp.publicKey, 1000)
+        .rpc();
+      minterConfig = await stablecoinProgram.account.minterConfig.fetch(
+        minterPda
+      );
+      expect(Number(minterConfig.mintAllowance)).to.equal(0);
+    });
+
+    it("propose_authority + accept_authority hands over master_authority", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
         stablecoinProgram.programId
       );
+      sss2Sdk.mintAddress = mintPda;
       const newAuthority = anchor.web3.Keypair.generate();
       const airdropSig = await connection.requestAirdrop(
         newAuthority.publicKey,
@@ -94,28 +320,52 @@ export function registerUnitSuccessSuite(ctx: TestContext): void {
         { signature: airdropSig, ...lb },
         "confirmed"
       );
-      await stablecoinProgram.methods
-        .transferAuthority(newAuthority.publicKey)
-        .accounts({
-          admin: authority.publicKey,
-          config: configPda,
-          mint: mintPda,
-        } as any)
+      await sss2Sdk
+        .proposeAuthority(authority.publicKey, "master", newAuthority.publicKey)
         .rpc();
-      const configAfter =
-        await stablecoinProgram.account.stablecoinConfig.fetch(configPda);
+      let configAfter = await sss2Sdk.getConfig();
       expect(configAfter.masterAuthority.toBase58()).to.equal(
+        authority.publicKey.toBase58()
+      );
+      expect(configAfter.pendingAuthority?.toBase58()).to.equal(
         newAuthority.publicKey.toBase58()
       );
-      await stablecoinProgram.methods
-        .transferAuthority(authority.publicKey)
-        .accounts({
-          admin: newAuthority.publicKey,
-          config: configPda,
-          mint: mintPda,
-        } as any)
+      await sss2Sdk
+        .acceptAuthority(newAuthority.publicKey, "master")
         .signers([newAuthority])
         .rpc();
+      configAfter = await sss2Sdk.getConfig();
+      expect(configAfter.masterAuthority.toBase58()).to.equal(
+        newAuthority.publicKey.toBase58()
+      );
+      expect(configAfter.pendingAuthority).to.be.null;
+
+      await sss2Sdk
+        .proposeAuthority(newAuthority.publicKey, "master", authority.publicKey)
+        .signers([newAuthority])
+        .rpc();
+      await sss2Sdk.acceptAuthority(authority.publicKey, "master").rpc();
+    });
+
+    it("cancel_authority_transfer clears a pending role proposal", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const candidate = anchor.web3.Keypair.generate();
+      await sss2Sdk
+        .proposeAuthority(authority.publicKey, "pauser", candidate.publicKey)
+        .rpc();
+      let roles = await sss2Sdk.getRoles();
+      expect(roles.pendingPauser?.toBase58()).to.equal(
+        candidate.publicKey.toBase58()
+      );
+      await sss2Sdk
+        .cancelAuthorityTransfer(authority.publicKey, "pauser")
+        .rpc();
+      roles = await sss2Sdk.getRoles();
+      expect(roles.pendingPauser).to.be.null;
     });
 
     it("freeze_account and thaw_account change token account state", async () => {
@@ -161,6 +411,45 @@ export function registerUnitSuccessSuite(ctx: TestContext): void {
       expect(acc.isFrozen).to.be.false;
     });
 
+    it("set_default_account_state makes new token accounts start frozen", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      await sss2Sdk.setDefaultAccountState(authority.publicKey, true).rpc();
+      expect((await sss2Sdk.getConfig()).defaultAccountFrozen).to.be.true;
+
+      const newHolder = anchor.web3.Keypair.generate();
+      const newAta = getAssociatedTokenAddressSync(
+        mintPda,
+        newHolder.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          createAssociatedTokenAccountInstruction(
+            authority.publicKey,
+            newAta,
+            newHolder.publicKey,
+            mintPda,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      );
+      const acc = await getAccount(
+        connection,
+        newAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(acc.isFrozen).to.be.true;
+
+      await sss2Sdk.setDefaultAccountState(authority.publicKey, false).rpc();
+      expect((await sss2Sdk.getConfig()).defaultAccountFrozen).to.be.false;
+    });
+
     it("remove_from_blacklist allows transfer after removal", async () => {
       const mintPda = SolanaStablecoin.getMintPDA(
         "SUSD",
@@ -201,5 +490,51 @@ export function registerUnitSuccessSuite(ctx: TestContext): void {
       );
       expect(sig).to.be.a("string");
     });
+
+    it("close_blacklist_entry closes the PDA and refunds rent to the recipient", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const entryPda = SolanaStablecoin.getBlacklistEntryPDA(
+        mintPda,
+        user2.publicKey,
+        stablecoinProgram.programId
+      );
+      const rent = await connection.getBalance(entryPda);
+      expect(rent).to.be.greaterThan(0);
+      const recipient = anchor.web3.Keypair.generate();
+      await complianceSdk
+        .closeBlacklistEntry(
+          authority.publicKey,
+          user2.publicKey,
+          recipient.publicKey
+        )
+        .rpc();
+      expect(await connection.getAccountInfo(entryPda)).to.be.null;
+      expect(await connection.getBalance(recipient.publicKey)).to.equal(rent);
+    });
+
+    it("remove_minter closes the minter config", async () => {
+      const mintPda = SolanaStablecoin.getMintPDA(
+        "SUSD",
+        stablecoinProgram.programId
+      );
+      sss2Sdk.mintAddress = mintPda;
+      const minterKp = anchor.web3.Keypair.generate();
+      await sss2Sdk
+        .addMinter(authority.publicKey, minterKp.publicKey, true, 1000)
+        .then((tx) => tx.rpc());
+      await sss2Sdk.removeMinter(authority.publicKey, minterKp.publicKey).rpc();
+      const minterPda = SolanaStablecoin.getMinterPDA(
+        mintPda,
+        minterKp.publicKey,
+        stablecoinProgram.programId
+      );
+      expect(
+        await stablecoinProgram.account.minterConfig.fetchNullable(minterPda)
+      ).to.be.null;
+    });
   });
 }
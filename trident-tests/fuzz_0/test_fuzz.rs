@@ -15,6 +15,7 @@ mod types;
 struct StablecoinTracker {
     initialized: bool,
     total_supply: u64,
+    total_minted: u64,
     is_paused: bool,
     mint_count: u64,
     burn_count: u64,
@@ -59,6 +60,7 @@ impl FuzzTest {
         let amount: u64 = rand::random::<u64>() % 1_000_000_000;
         let amount = if amount == 0 { 1 } else { amount };
         self.tracker.total_supply = self.tracker.total_supply.saturating_add(amount);
+        self.tracker.total_minted = self.tracker.total_minted.saturating_add(amount);
         self.tracker.mint_count += 1;
     }
 
@@ -98,10 +100,10 @@ impl FuzzTest {
         if !self.tracker.initialized {
             return;
         }
-        // Invariant: total supply is non-negative (saturating_sub used in flow_burn)
+        // Invariant: burns never take supply above what was minted
         assert!(
-            self.tracker.total_supply <= u64::MAX,
-            "total_supply overflow"
+            self.tracker.total_supply <= self.tracker.total_minted,
+            "total_supply exceeds total minted"
         );
     }
 }